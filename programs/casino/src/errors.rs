@@ -253,4 +253,31 @@ pub enum CasinoError {
     
     #[msg("Responsible gambling limit reached")]
     ResponsibleGamblingLimitReached,
+    
+    #[msg("Server seed hash has already been used")]
+    ServerSeedAlreadyUsed,
+    
+    #[msg("Duel is not open")]
+    DuelNotOpen,
+    
+    #[msg("Cannot join your own duel")]
+    CannotJoinOwnDuel,
+    
+    #[msg("Duel cannot be settled yet")]
+    DuelNotSettleable,
+    
+    #[msg("Duel cannot be cancelled")]
+    CannotCancelDuel,
+    
+    #[msg("Invalid side bet")]
+    InvalidSideBet,
+    
+    #[msg("Token mint uses an unsupported extension")]
+    UnsupportedMintExtension,
+    
+    #[msg("Proposal has expired")]
+    ProposalExpired,
+    
+    #[msg("Proposal does not have enough approvals")]
+    ProposalThresholdNotMet,
 }
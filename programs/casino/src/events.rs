@@ -0,0 +1,593 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{
+    GameStatus, GameType, MaintenanceWindow, ParameterUpdate, PauseReason, PendingConfigChange, ProposalAction, Role,
+};
+
+#[event]
+#[derive(Debug)]
+pub struct GameCreated {
+    pub game: Pubkey,
+    pub casino: Pubkey,
+    pub player: Pubkey,
+    pub game_type: GameType,
+    pub bet_amount: u64,
+    pub session_id: u64,
+    pub server_seed_hash: String,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct ServerSeedCommitted {
+    pub casino: Pubkey,
+    pub seed_commitment: Pubkey,
+    pub server_seed_hash: String,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct GameResolved {
+    pub game: Pubkey,
+    pub casino: Pubkey,
+    pub player: Pubkey,
+    pub outcome: Vec<u8>,
+    pub multiplier: u64,
+    pub payout: u64,
+    pub server_seed: String,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct WinningsClaimed {
+    pub game: Pubkey,
+    pub casino: Pubkey,
+    pub player: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct GameCancelled {
+    pub game: Pubkey,
+    pub casino: Pubkey,
+    pub player: Pubkey,
+    pub refund: u64,
+    pub cancellation_fee: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct GameExpired {
+    pub game: Pubkey,
+    pub casino: Pubkey,
+    pub player: Pubkey,
+    pub refund: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct GameClosed {
+    pub game: Pubkey,
+    pub casino: Pubkey,
+    pub player: Pubkey,
+    pub game_type: GameType,
+    pub status: GameStatus,
+    pub bet_amount: u64,
+    pub outcome: Vec<u8>,
+    pub multiplier: u64,
+    pub payout: u64,
+    pub server_seed: Option<String>,
+    pub created_at: i64,
+    pub resolved_at: Option<i64>,
+    pub closed_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct CasinoConfigUpdated {
+    pub casino: Pubkey,
+    pub authority: Pubkey,
+    pub house_edge: u16,
+    pub min_bet: u64,
+    pub max_bet: u64,
+    pub is_active: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct CasinoPaused {
+    pub casino: Pubkey,
+    pub authority: Pubkey,
+    pub reason: PauseReason,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct CasinoUnpaused {
+    pub casino: Pubkey,
+    pub authority: Pubkey,
+    pub reason: PauseReason,
+    pub resolve_only: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct MaintenanceScheduled {
+    pub casino: Pubkey,
+    pub authority: Pubkey,
+    pub window: Option<MaintenanceWindow>,
+    pub timestamp: i64,
+}
+
+/// `role` is None for transfers of the casino authority itself
+#[event]
+#[derive(Debug)]
+pub struct AuthorityTransferProposed {
+    pub casino: Pubkey,
+    pub role: Option<Role>,
+    pub current: Pubkey,
+    pub proposed: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct AuthorityTransferAccepted {
+    pub casino: Pubkey,
+    pub role: Option<Role>,
+    pub previous: Pubkey,
+    pub holder: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct AuthorityTransferCancelled {
+    pub casino: Pubkey,
+    pub role: Option<Role>,
+    pub proposed: Pubkey,
+    pub timestamp: i64,
+}
+
+/// `mint` is set when the change targets a `CasinoMint`'s overrides
+#[event]
+#[derive(Debug)]
+pub struct ConfigChangeQueued {
+    pub casino: Pubkey,
+    pub mint: Option<Pubkey>,
+    pub change: PendingConfigChange,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct ConfigChangeCancelled {
+    pub casino: Pubkey,
+    pub mint: Option<Pubkey>,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct ConfigChangeApplied {
+    pub casino: Pubkey,
+    pub mint: Option<Pubkey>,
+    pub change: PendingConfigChange,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct ProposalCreated {
+    pub multisig: Pubkey,
+    pub proposal: Pubkey,
+    pub proposer: Pubkey,
+    pub action: ProposalAction,
+    pub expires_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct ProposalApproved {
+    pub proposal: Pubkey,
+    pub signer: Pubkey,
+    pub approvals: u8,
+    pub threshold: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct ProposalExecuted {
+    pub multisig: Pubkey,
+    pub proposal: Pubkey,
+    pub action: ProposalAction,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct ProposalCancelled {
+    pub multisig: Pubkey,
+    pub proposal: Pubkey,
+    pub proposer: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct GovernanceProposalCreated {
+    pub casino: Pubkey,
+    pub proposal: Pubkey,
+    pub proposer: Pubkey,
+    pub update: ParameterUpdate,
+    pub voting_ends_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct VoteCast {
+    pub proposal: Pubkey,
+    pub voter: Pubkey,
+    pub weight: u64,
+    pub support: bool,
+    pub votes_for: u64,
+    pub votes_against: u64,
+    pub timestamp: i64,
+}
+
+/// `queued` holds the house edge or fee increase left waiting on the timelock;
+/// `passed` is false for a proposal that expired before it was executed
+#[event]
+#[derive(Debug)]
+pub struct GovernanceProposalFinalized {
+    pub casino: Pubkey,
+    pub proposal: Pubkey,
+    pub passed: bool,
+    pub votes_for: u64,
+    pub votes_against: u64,
+    pub queued: Option<PendingConfigChange>,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct TreasuryWithdrawn {
+    pub casino: Pubkey,
+    pub mint: Option<Pubkey>,
+    pub authority: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct DuelSettled {
+    pub duel: Pubkey,
+    pub casino: Pubkey,
+    pub winner: Option<Pubkey>,
+    pub payout: u64,
+    pub treasury_fee: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct RouletteRoundSpun {
+    pub round: Pubkey,
+    pub casino: Pubkey,
+    pub result: u8,
+    pub server_seed: String,
+    pub slot_hash: [u8; 32],
+    pub total_staked: u64,
+    pub winning_payout: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct JackpotWon {
+    pub jackpot: Pubkey,
+    pub casino: Pubkey,
+    pub game: Pubkey,
+    pub player: Pubkey,
+    pub payout: u64,
+    pub payout_usd: Option<u64>,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct LiquidityDeposited {
+    pub pool: Pubkey,
+    pub casino: Pubkey,
+    pub provider: Pubkey,
+    pub amount: u64,
+    pub shares: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct LiquidityWithdrawalRequested {
+    pub pool: Pubkey,
+    pub provider: Pubkey,
+    pub shares: u64,
+    pub unlocks_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct LiquidityWithdrawn {
+    pub pool: Pubkey,
+    pub casino: Pubkey,
+    pub provider: Pubkey,
+    pub amount: u64,
+    pub shares: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct CircuitBreakerTripped {
+    pub casino: Pubkey,
+    pub mint: Option<Pubkey>,
+    pub window_pnl: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct CircuitBreakerReset {
+    pub casino: Pubkey,
+    pub mint: Option<Pubkey>,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct PlayerBanned {
+    pub casino: Pubkey,
+    pub player: Pubkey,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+#[derive(Debug)]
+pub struct PlayerUnbanned {
+    pub casino: Pubkey,
+    pub player: Pubkey,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Off-chain decoding of casino events from transaction logs
+#[cfg(not(target_os = "solana"))]
+pub mod decoder {
+    use super::*;
+    use anchor_lang::Discriminator;
+    use base64::Engine;
+
+    const PROGRAM_DATA: &str = "Program data: ";
+
+    #[derive(Debug)]
+    pub enum CasinoEvent {
+        GameCreated(GameCreated),
+        ServerSeedCommitted(ServerSeedCommitted),
+        GameResolved(GameResolved),
+        WinningsClaimed(WinningsClaimed),
+        GameCancelled(GameCancelled),
+        GameExpired(GameExpired),
+        GameClosed(GameClosed),
+        CasinoConfigUpdated(CasinoConfigUpdated),
+        CasinoPaused(CasinoPaused),
+        CasinoUnpaused(CasinoUnpaused),
+        MaintenanceScheduled(MaintenanceScheduled),
+        AuthorityTransferProposed(AuthorityTransferProposed),
+        AuthorityTransferAccepted(AuthorityTransferAccepted),
+        AuthorityTransferCancelled(AuthorityTransferCancelled),
+        ConfigChangeQueued(ConfigChangeQueued),
+        ConfigChangeCancelled(ConfigChangeCancelled),
+        ConfigChangeApplied(ConfigChangeApplied),
+        ProposalCreated(ProposalCreated),
+        ProposalApproved(ProposalApproved),
+        ProposalExecuted(ProposalExecuted),
+        ProposalCancelled(ProposalCancelled),
+        GovernanceProposalCreated(GovernanceProposalCreated),
+        VoteCast(VoteCast),
+        GovernanceProposalFinalized(GovernanceProposalFinalized),
+        TreasuryWithdrawn(TreasuryWithdrawn),
+        DuelSettled(DuelSettled),
+        RouletteRoundSpun(RouletteRoundSpun),
+        JackpotWon(JackpotWon),
+        LiquidityDeposited(LiquidityDeposited),
+        LiquidityWithdrawalRequested(LiquidityWithdrawalRequested),
+        LiquidityWithdrawn(LiquidityWithdrawn),
+        CircuitBreakerTripped(CircuitBreakerTripped),
+        CircuitBreakerReset(CircuitBreakerReset),
+        PlayerBanned(PlayerBanned),
+        PlayerUnbanned(PlayerUnbanned),
+    }
+
+    impl CasinoEvent {
+        /// Decodes a single event from its discriminator-prefixed bytes
+        pub fn try_from_bytes(data: &[u8]) -> Option<Self> {
+            if data.len() < 8 {
+                return None;
+            }
+            let (discriminator, mut payload) = data.split_at(8);
+
+            macro_rules! decode {
+                ($($event:ident),* $(,)?) => {
+                    $(
+                        if discriminator == $event::DISCRIMINATOR {
+                            return $event::deserialize(&mut payload).ok().map(CasinoEvent::$event);
+                        }
+                    )*
+                };
+            }
+
+            decode!(
+                GameCreated,
+                ServerSeedCommitted,
+                GameResolved,
+                WinningsClaimed,
+                GameCancelled,
+                GameExpired,
+                GameClosed,
+                CasinoConfigUpdated,
+                CasinoPaused,
+                CasinoUnpaused,
+                MaintenanceScheduled,
+                AuthorityTransferProposed,
+                AuthorityTransferAccepted,
+                AuthorityTransferCancelled,
+                ConfigChangeQueued,
+                ConfigChangeCancelled,
+                ConfigChangeApplied,
+                ProposalCreated,
+                ProposalApproved,
+                ProposalExecuted,
+                ProposalCancelled,
+                GovernanceProposalCreated,
+                VoteCast,
+                GovernanceProposalFinalized,
+                TreasuryWithdrawn,
+                DuelSettled,
+                RouletteRoundSpun,
+                JackpotWon,
+                LiquidityDeposited,
+                LiquidityWithdrawalRequested,
+                LiquidityWithdrawn,
+                CircuitBreakerTripped,
+                CircuitBreakerReset,
+                PlayerBanned,
+                PlayerUnbanned,
+            );
+
+            None
+        }
+    }
+
+    /// Extracts casino events from transaction logs.
+    ///
+    /// Tracks the invocation stack so only `Program data:` lines emitted by the
+    /// casino program itself are decoded, including when it is invoked via CPI.
+    pub fn parse_logs<S: AsRef<str>>(logs: &[S]) -> Vec<CasinoEvent> {
+        let program_id = crate::ID.to_string();
+        let mut stack: Vec<&str> = Vec::new();
+        let mut events = Vec::new();
+
+        for log in logs {
+            let log = log.as_ref();
+
+            if let Some(data) = log.strip_prefix(PROGRAM_DATA) {
+                if stack.last() == Some(&program_id.as_str()) {
+                    if let Ok(bytes) = base64::engine::general_purpose::STANDARD.decode(data) {
+                        events.extend(CasinoEvent::try_from_bytes(&bytes));
+                    }
+                }
+                continue;
+            }
+
+            let mut parts = log.split_whitespace();
+            if parts.next() != Some("Program") {
+                continue;
+            }
+            match (parts.next(), parts.next()) {
+                (Some(program), Some("invoke")) => stack.push(program),
+                (Some(_), Some("success")) | (Some(_), Some("failed:")) => {
+                    stack.pop();
+                },
+                _ => {},
+            }
+        }
+
+        events
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use anchor_lang::Event;
+
+        fn paused(timestamp: i64) -> CasinoPaused {
+            CasinoPaused {
+                casino: Pubkey::new_unique(),
+                authority: Pubkey::new_unique(),
+                reason: PauseReason::default(),
+                timestamp,
+            }
+        }
+
+        fn data_log(event: &impl Event) -> String {
+            format!("{}{}", PROGRAM_DATA, base64::engine::general_purpose::STANDARD.encode(event.data()))
+        }
+
+        #[test]
+        fn decodes_event_bytes() {
+            let event = paused(42);
+            match CasinoEvent::try_from_bytes(&event.data()) {
+                Some(CasinoEvent::CasinoPaused(decoded)) => {
+                    assert_eq!(decoded.casino, event.casino);
+                    assert_eq!(decoded.timestamp, 42);
+                },
+                other => panic!("unexpected {:?}", other),
+            }
+        }
+
+        #[test]
+        fn rejects_short_and_unknown_bytes() {
+            assert!(CasinoEvent::try_from_bytes(&[1, 2, 3]).is_none());
+            assert!(CasinoEvent::try_from_bytes(&[0u8; 64]).is_none());
+
+            // Known discriminator with a truncated payload
+            let data = paused(1).data();
+            assert!(CasinoEvent::try_from_bytes(&data[..data.len() - 1]).is_none());
+        }
+
+        #[test]
+        fn parses_only_casino_program_data() {
+            let casino = crate::ID.to_string();
+            let other = Pubkey::new_unique().to_string();
+            let logs = vec![
+                format!("Program {} invoke [1]", other),
+                data_log(&paused(1)),
+                format!("Program {} invoke [2]", casino),
+                data_log(&paused(2)),
+                format!("Program {} invoke [3]", other),
+                data_log(&paused(3)),
+                format!("Program {} success", other),
+                data_log(&paused(4)),
+                "Program data: not base64!".to_string(),
+                format!("Program {} success", casino),
+                data_log(&paused(5)),
+                format!("Program {} success", other),
+            ];
+
+            let timestamps: Vec<i64> = parse_logs(&logs)
+                .into_iter()
+                .map(|event| match event {
+                    CasinoEvent::CasinoPaused(event) => event.timestamp,
+                    other => panic!("unexpected {:?}", other),
+                })
+                .collect();
+            assert_eq!(timestamps, vec![2, 4]);
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::CasinoError;
+use crate::events::AuthorityTransferAccepted;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    #[account(mut)]
+    pub casino: Account<'info, Casino>,
+
+    /// Required when the transfer is for a role
+    #[account(
+        mut,
+        seeds = [ROLES_SEED, casino.key().as_ref()],
+        bump = roles.bump,
+    )]
+    pub roles: Option<Account<'info, CasinoRoles>>,
+
+    /// Key the role or authority was proposed to
+    pub new_holder: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<AcceptAuthority>, role: Option<Role>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let holder = ctx.accounts.new_holder.key();
+    let casino = &mut ctx.accounts.casino;
+    let previous = match role {
+        Some(role) => ctx.accounts.roles
+            .as_deref_mut()
+            .ok_or(CasinoError::AccountNotInitialized)?
+            .accept(role, holder, now)?,
+        None => casino.accept_authority(holder, now)?,
+    };
+
+    emit!(AuthorityTransferAccepted {
+        casino: casino.key(),
+        role,
+        previous,
+        holder,
+        timestamp: now,
+    });
+
+    Ok(())
+}
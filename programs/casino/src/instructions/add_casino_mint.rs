@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::errors::CasinoError;
+use crate::state::*;
+use crate::vault;
+
+#[derive(Accounts)]
+pub struct AddCasinoMint<'info> {
+    pub casino: Account<'info, Casino>,
+
+    #[account(
+        seeds = [ROLES_SEED, casino.key().as_ref()],
+        bump = roles.bump,
+        constraint = roles.has_role(Role::Operator, &authority.key()) @ CasinoError::InsufficientPermissions,
+    )]
+    pub roles: Account<'info, CasinoRoles>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = CasinoMint::LEN,
+        seeds = [CASINO_MINT_SEED, casino.key().as_ref(), mint.key().as_ref()],
+        bump
+    )]
+    pub casino_mint: Account<'info, CasinoMint>,
+
+    #[account(
+        init,
+        payer = payer,
+        seeds = [VAULT_SEED, casino_mint.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = casino,
+        token::token_program = token_program,
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        constraint = mint.key() != casino.token_mint @ CasinoError::InvalidTokenMint,
+        constraint = mint.key() != spl_token::native_mint::ID @ CasinoError::InvalidTokenMint,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    pub authority: Signer<'info>,
+
+    /// Pays rent for the new accounts
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler(
+    ctx: Context<AddCasinoMint>,
+    min_bet: u64,
+    max_bet: u64,
+    max_payout: u64,
+    house_edge: Option<u16>,
+    treasury_fee: Option<u16>,
+) -> Result<()> {
+    vault::validate_mint(&ctx.accounts.mint.to_account_info())?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let casino_mint = &mut ctx.accounts.casino_mint;
+    casino_mint.casino = ctx.accounts.casino.key();
+    casino_mint.mint = ctx.accounts.mint.key();
+    casino_mint.vault = ctx.accounts.vault.key();
+    casino_mint.min_bet = min_bet;
+    casino_mint.max_bet = max_bet;
+    casino_mint.max_payout = max_payout;
+    casino_mint.house_edge = house_edge;
+    casino_mint.treasury_fee = treasury_fee;
+    casino_mint.pending_config = None;
+    casino_mint.is_active = true;
+    casino_mint.reserved_liability = 0;
+    casino_mint.treasury_owed = 0;
+    casino_mint.circuit_breaker = CircuitBreaker::default();
+    casino_mint.stats = CasinoStats {
+        total_games: 0,
+        total_volume: 0,
+        total_profit: 0,
+        total_payouts: 0,
+        active_players: 0,
+        house_edge_collected: 0,
+        treasury_fees_collected: 0,
+        jackpot_contributions: 0,
+        jackpot_payouts: 0,
+        games_expired: 0,
+        volume_usd: 0,
+        jackpot_payouts_usd: 0,
+    };
+    casino_mint.created_at = now;
+    casino_mint.updated_at = now;
+    casino_mint.bump = ctx.bumps.casino_mint;
+
+    casino_mint.validate_config()
+}
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::CasinoError;
+use crate::events::ConfigChangeApplied;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct ApplyConfigChange<'info> {
+    #[account(mut)]
+    pub casino: Account<'info, Casino>,
+
+    /// Mint whose queued change is applied, or None for the casino defaults
+    #[account(
+        mut,
+        has_one = casino,
+    )]
+    pub casino_mint: Option<Account<'info, CasinoMint>>,
+}
+
+pub fn handler(ctx: Context<ApplyConfigChange>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+
+    let (change, mint) = match &mut ctx.accounts.casino_mint {
+        Some(casino_mint) => {
+            let change = casino_mint.pending_config.ok_or(CasinoError::InvalidStateTransition)?;
+            require!(change.is_effective(now), CasinoError::CooldownPeriodActive);
+            casino_mint.apply_config_change(&change)?;
+            casino_mint.pending_config = None;
+            casino_mint.updated_at = now;
+            (change, Some(casino_mint.mint))
+        },
+        None => {
+            let casino = &mut ctx.accounts.casino;
+            let change = casino.pending_config.ok_or(CasinoError::InvalidStateTransition)?;
+            require!(change.is_effective(now), CasinoError::CooldownPeriodActive);
+            casino.apply_config_change(&change)?;
+            casino.pending_config = None;
+            casino.updated_at = now;
+            (change, None)
+        },
+    };
+
+    emit!(ConfigChangeApplied {
+        casino: ctx.accounts.casino.key(),
+        mint,
+        change,
+        timestamp: now,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::events::ProposalApproved;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct ApproveProposal<'info> {
+    #[account(
+        seeds = [MULTISIG_SEED, multisig.casino.as_ref()],
+        bump = multisig.bump
+    )]
+    pub multisig: Account<'info, Multisig>,
+
+    #[account(
+        mut,
+        has_one = multisig,
+        seeds = [PROPOSAL_SEED, multisig.key().as_ref(), &proposal.proposal_id.to_le_bytes()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    pub signer: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<ApproveProposal>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let multisig = &ctx.accounts.multisig;
+    let signer_index = multisig.signer_index(&ctx.accounts.signer.key())?;
+
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.validate_open(multisig, now)?;
+    proposal.approve(signer_index)?;
+
+    emit!(ProposalApproved {
+        proposal: proposal.key(),
+        signer: ctx.accounts.signer.key(),
+        approvals: proposal.approval_count(),
+        threshold: multisig.threshold,
+        timestamp: now,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::CasinoError;
+use crate::events::PlayerBanned;
+use crate::state::*;
+
+#[derive(Accounts)]
+#[instruction(player: Pubkey)]
+pub struct BanPlayer<'info> {
+    pub casino: Account<'info, Casino>,
+
+    #[account(
+        seeds = [ROLES_SEED, casino.key().as_ref()],
+        bump = roles.bump,
+        constraint = roles.has_role(Role::Compliance, &authority.key()) @ CasinoError::InsufficientPermissions,
+    )]
+    pub roles: Account<'info, CasinoRoles>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = PlayerBan::LEN,
+        seeds = [PLAYER_BAN_SEED, casino.key().as_ref(), player.as_ref()],
+        bump
+    )]
+    pub player_ban: Account<'info, PlayerBan>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<BanPlayer>, player: Pubkey) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let player_ban = &mut ctx.accounts.player_ban;
+    player_ban.casino = ctx.accounts.casino.key();
+    player_ban.player = player;
+    player_ban.banned_by = ctx.accounts.authority.key();
+    player_ban.banned_at = now;
+    player_ban.bump = ctx.bumps.player_ban;
+
+    emit!(PlayerBanned {
+        casino: player_ban.casino,
+        player,
+        authority: player_ban.banned_by,
+        timestamp: now,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::CasinoError;
+use crate::events::AuthorityTransferCancelled;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct CancelAuthorityTransfer<'info> {
+    #[account(
+        mut,
+        has_one = authority @ CasinoError::Unauthorized,
+    )]
+    pub casino: Account<'info, Casino>,
+
+    /// Required when the transfer is for a role
+    #[account(
+        mut,
+        seeds = [ROLES_SEED, casino.key().as_ref()],
+        bump = roles.bump,
+    )]
+    pub roles: Option<Account<'info, CasinoRoles>>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<CancelAuthorityTransfer>, role: Option<Role>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let casino = &mut ctx.accounts.casino;
+
+    let proposed = match role {
+        Some(role) => ctx.accounts.roles
+            .as_deref_mut()
+            .ok_or(CasinoError::AccountNotInitialized)?
+            .cancel_transfer(role, now),
+        None => {
+            let proposed = casino.pending_authority.take();
+            casino.updated_at = now;
+            proposed
+        },
+    };
+    let proposed = proposed.ok_or(CasinoError::InvalidStateTransition)?;
+
+    emit!(AuthorityTransferCancelled {
+        casino: casino.key(),
+        role,
+        proposed,
+        timestamp: now,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::CasinoError;
+use crate::events::ConfigChangeCancelled;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct CancelConfigChange<'info> {
+    #[account(mut)]
+    pub casino: Account<'info, Casino>,
+
+    #[account(
+        seeds = [ROLES_SEED, casino.key().as_ref()],
+        bump = roles.bump,
+        constraint = roles.has_role(Role::Operator, &authority.key()) @ CasinoError::InsufficientPermissions,
+    )]
+    pub roles: Account<'info, CasinoRoles>,
+
+    /// Mint whose queued change is dropped, or None for the casino defaults
+    #[account(
+        mut,
+        has_one = casino,
+    )]
+    pub casino_mint: Option<Account<'info, CasinoMint>>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<CancelConfigChange>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let by_governance = match &ctx.accounts.casino_mint {
+        Some(casino_mint) => casino_mint.pending_config.map_or(false, |change| change.by_governance),
+        None => ctx.accounts.casino.pending_config.map_or(false, |change| change.by_governance),
+    };
+    require!(!by_governance, CasinoError::OperationNotAllowed);
+
+    let (pending, mint) = match &mut ctx.accounts.casino_mint {
+        Some(casino_mint) => {
+            casino_mint.updated_at = now;
+            (casino_mint.pending_config.take(), Some(casino_mint.mint))
+        },
+        None => {
+            let casino = &mut ctx.accounts.casino;
+            casino.updated_at = now;
+            (casino.pending_config.take(), None)
+        },
+    };
+    require!(pending.is_some(), CasinoError::InvalidStateTransition);
+
+    emit!(ConfigChangeCancelled {
+        casino: ctx.accounts.casino.key(),
+        mint,
+        timestamp: now,
+    });
+
+    Ok(())
+}
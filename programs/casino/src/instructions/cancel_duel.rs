@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, CloseAccount, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+use crate::errors::CasinoError;
+use crate::state::*;
+use crate::vault;
+
+#[derive(Accounts)]
+pub struct CancelDuel<'info> {
+    #[account(
+        mut,
+        has_one = creator,
+        seeds = [DUEL_SEED, duel.casino.as_ref(), creator.key().as_ref(), &duel.duel_id.to_le_bytes()],
+        bump = duel.bump
+    )]
+    pub duel: Account<'info, Duel>,
+
+    #[account(
+        mut,
+        seeds = [DUEL_ESCROW_SEED, duel.key().as_ref()],
+        bump,
+    )]
+    pub escrow: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        address = escrow.mint @ CasinoError::InvalidTokenMint,
+    )]
+    pub token_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        constraint = creator_token_account.owner == creator.key() @ CasinoError::InvalidTokenAccount,
+        constraint = creator_token_account.mint == escrow.mint @ CasinoError::InvalidTokenMint,
+    )]
+    pub creator_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handler(ctx: Context<CancelDuel>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let duel = &ctx.accounts.duel;
+    require!(duel.can_be_cancelled(now), CasinoError::CannotCancelDuel);
+
+    let duel_id_bytes = duel.duel_id.to_le_bytes();
+    let seeds = &[
+        DUEL_SEED,
+        duel.casino.as_ref(),
+        duel.creator.as_ref(),
+        &duel_id_bytes,
+        &[duel.bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.escrow.to_account_info(),
+                mint: ctx.accounts.token_mint.to_account_info(),
+                to: ctx.accounts.creator_token_account.to_account_info(),
+                authority: ctx.accounts.duel.to_account_info(),
+            },
+            signer,
+        ),
+        ctx.accounts.escrow.amount,
+        ctx.accounts.token_mint.decimals,
+    )?;
+
+    vault::harvest_withheld_fees(
+        &ctx.accounts.escrow.to_account_info(),
+        &ctx.accounts.token_mint.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+    )?;
+    token_interface::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.escrow.to_account_info(),
+            destination: ctx.accounts.creator.to_account_info(),
+            authority: ctx.accounts.duel.to_account_info(),
+        },
+        signer,
+    ))?;
+
+    let duel = &mut ctx.accounts.duel;
+    duel.status = DuelStatus::Cancelled;
+    duel.settled_at = Some(now);
+
+    Ok(())
+}
@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenInterface};
+
+use crate::errors::CasinoError;
+use crate::events::GameCancelled;
+use crate::state::*;
+use crate::vault;
+
+#[derive(Accounts)]
+pub struct CancelGame<'info> {
+    #[account(mut)]
+    pub casino: Account<'info, Casino>,
+
+    /// Required when the game was played in a non-primary mint
+    #[account(
+        mut,
+        has_one = casino,
+        seeds = [CASINO_MINT_SEED, casino.key().as_ref(), casino_mint.mint.as_ref()],
+        bump = casino_mint.bump
+    )]
+    pub casino_mint: Option<Account<'info, CasinoMint>>,
+
+    #[account(
+        mut,
+        has_one = casino,
+        has_one = player,
+    )]
+    pub game: Account<'info, Game>,
+
+    /// CHECK: token account or native SOL vault PDA, validated in `vault`
+    #[account(mut)]
+    pub vault: UncheckedAccount<'info>,
+
+    /// Mint of the vault, validated in `vault`
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: player token account, or the player's wallet for native SOL casinos; validated in `vault`
+    #[account(mut)]
+    pub player_token_account: UncheckedAccount<'info>,
+
+    pub player: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<CancelGame>) -> Result<()> {
+    let game = &mut ctx.accounts.game;
+    game.cancel_game()?;
+
+    let total_wager = game.total_wager()?;
+    let potential_payout = game.potential_payout()?;
+    let cancellation_fee = ctx.accounts.casino.calculate_cancellation_fee(total_wager)?;
+    let refund = total_wager
+        .checked_sub(cancellation_fee)
+        .ok_or(CasinoError::ArithmeticUnderflow)?;
+    let vault_ref = game.vault_ref(&ctx.accounts.casino, ctx.accounts.casino_mint.as_deref())?;
+
+    let received = vault::pay_out(
+        &ctx.accounts.casino,
+        vault_ref,
+        &ctx.accounts.vault,
+        &ctx.accounts.mint,
+        &ctx.accounts.player_token_account,
+        &ctx.accounts.player.key(),
+        &ctx.accounts.token_program,
+        &ctx.accounts.system_program,
+        refund,
+    )?;
+
+    match ctx.accounts.casino_mint.as_mut() {
+        Some(casino_mint) => casino_mint.record_cancelled_game(potential_payout, cancellation_fee),
+        None => ctx.accounts.casino.record_cancelled_game(potential_payout, cancellation_fee),
+    }
+
+    emit!(GameCancelled {
+        game: ctx.accounts.game.key(),
+        casino: ctx.accounts.casino.key(),
+        player: ctx.accounts.player.key(),
+        refund: received,
+        cancellation_fee,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct CancelLiquidityWithdrawal<'info> {
+    #[account(
+        mut,
+        has_one = share_mint,
+        seeds = [LIQUIDITY_POOL_SEED, liquidity_pool.casino.as_ref()],
+        bump = liquidity_pool.bump
+    )]
+    pub liquidity_pool: Account<'info, LiquidityPool>,
+
+    pub share_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [LP_ESCROW_SEED, liquidity_pool.key().as_ref()],
+        bump,
+    )]
+    pub share_escrow: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        close = provider,
+        has_one = provider,
+        seeds = [LP_WITHDRAWAL_SEED, liquidity_pool.key().as_ref(), provider.key().as_ref()],
+        bump = withdrawal_request.bump
+    )]
+    pub withdrawal_request: Account<'info, WithdrawalRequest>,
+
+    #[account(
+        mut,
+        token::mint = share_mint,
+        token::authority = provider,
+    )]
+    pub provider_share_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub provider: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handler(ctx: Context<CancelLiquidityWithdrawal>) -> Result<()> {
+    let liquidity_pool = &ctx.accounts.liquidity_pool;
+    let shares = ctx.accounts.withdrawal_request.shares;
+
+    let seeds = &[LIQUIDITY_POOL_SEED, liquidity_pool.casino.as_ref(), &[liquidity_pool.bump]];
+    let signer = &[&seeds[..]];
+
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.share_escrow.to_account_info(),
+                mint: ctx.accounts.share_mint.to_account_info(),
+                to: ctx.accounts.provider_share_account.to_account_info(),
+                authority: ctx.accounts.liquidity_pool.to_account_info(),
+            },
+            signer,
+        ),
+        shares,
+        ctx.accounts.share_mint.decimals,
+    )?;
+
+    let liquidity_pool = &mut ctx.accounts.liquidity_pool;
+    liquidity_pool.pending_shares = liquidity_pool.pending_shares.saturating_sub(shares);
+    liquidity_pool.updated_at = Clock::get()?.unix_timestamp;
+
+    Ok(())
+}
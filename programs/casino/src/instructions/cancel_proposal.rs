@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::events::ProposalCancelled;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct CancelProposal<'info> {
+    #[account(
+        seeds = [MULTISIG_SEED, multisig.casino.as_ref()],
+        bump = multisig.bump
+    )]
+    pub multisig: Account<'info, Multisig>,
+
+    #[account(
+        mut,
+        has_one = multisig,
+        has_one = proposer,
+        seeds = [PROPOSAL_SEED, multisig.key().as_ref(), &proposal.proposal_id.to_le_bytes()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    pub proposer: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<CancelProposal>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let multisig = &ctx.accounts.multisig;
+    // Proposers removed from the signer set lose the right to withdraw
+    multisig.signer_index(&ctx.accounts.proposer.key())?;
+
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.cancel(multisig, now)?;
+
+    emit!(ProposalCancelled {
+        multisig: multisig.key(),
+        proposal: proposal.key(),
+        proposer: proposal.proposer,
+        timestamp: now,
+    });
+
+    Ok(())
+}
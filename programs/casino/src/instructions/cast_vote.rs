@@ -0,0 +1,103 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+use crate::errors::CasinoError;
+use crate::events::VoteCast;
+use crate::state::*;
+use crate::vault;
+
+#[derive(Accounts)]
+pub struct CastVote<'info> {
+    #[account(
+        has_one = token_mint @ CasinoError::InvalidTokenMint,
+    )]
+    pub casino: Account<'info, Casino>,
+
+    #[account(
+        mut,
+        has_one = casino,
+        seeds = [GOVERNANCE_PROPOSAL_SEED, casino.key().as_ref(), proposal.proposer.as_ref(), &proposal.proposal_id.to_le_bytes()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, GovernanceProposal>,
+
+    #[account(
+        init,
+        payer = voter,
+        space = VoteRecord::LEN,
+        seeds = [GOVERNANCE_VOTE_SEED, proposal.key().as_ref(), voter.key().as_ref()],
+        bump
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+
+    #[account(
+        init,
+        payer = voter,
+        seeds = [GOVERNANCE_ESCROW_SEED, vote_record.key().as_ref()],
+        bump,
+        token::mint = token_mint,
+        token::authority = vote_record,
+        token::token_program = token_program,
+    )]
+    pub escrow: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        constraint = voter_token_account.owner == voter.key() @ CasinoError::InvalidTokenAccount,
+        constraint = voter_token_account.mint == token_mint.key() @ CasinoError::InvalidTokenMint,
+    )]
+    pub voter_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler(ctx: Context<CastVote>, amount: u64, support: bool) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    require!(ctx.accounts.proposal.is_voting(now), CasinoError::OperationNotAllowed);
+    require!(amount > 0, CasinoError::InvalidInstructionData);
+
+    token_interface::transfer_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.voter_token_account.to_account_info(),
+                mint: ctx.accounts.token_mint.to_account_info(),
+                to: ctx.accounts.escrow.to_account_info(),
+                authority: ctx.accounts.voter.to_account_info(),
+            },
+        ),
+        amount,
+        ctx.accounts.token_mint.decimals,
+    )?;
+    let weight = vault::amount_after_fee(&ctx.accounts.token_mint.to_account_info(), amount)?;
+
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.record_vote(support, weight)?;
+
+    let vote_record = &mut ctx.accounts.vote_record;
+    vote_record.proposal = proposal.key();
+    vote_record.voter = ctx.accounts.voter.key();
+    vote_record.weight = weight;
+    vote_record.support = support;
+    vote_record.voted_at = now;
+    vote_record.bump = ctx.bumps.vote_record;
+
+    emit!(VoteCast {
+        proposal: proposal.key(),
+        voter: vote_record.voter,
+        weight,
+        support,
+        votes_for: proposal.votes_for,
+        votes_against: proposal.votes_against,
+        timestamp: now,
+    });
+
+    Ok(())
+}
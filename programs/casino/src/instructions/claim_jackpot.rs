@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenInterface};
+
+use crate::errors::CasinoError;
+use crate::events::JackpotWon;
+use crate::oracle;
+use crate::state::*;
+use crate::vault;
+
+#[derive(Accounts)]
+pub struct ClaimJackpot<'info> {
+    #[account(mut)]
+    pub casino: Account<'info, Casino>,
+
+    #[account(
+        mut,
+        has_one = casino,
+        seeds = [JACKPOT_SEED, casino.key().as_ref()],
+        bump = jackpot.bump
+    )]
+    pub jackpot: Account<'info, Jackpot>,
+
+    #[account(
+        mut,
+        has_one = casino,
+        has_one = player,
+    )]
+    pub game: Account<'info, Game>,
+
+    /// CHECK: token account or native SOL vault PDA, validated against `casino.vault`
+    #[account(
+        mut,
+        address = casino.vault @ CasinoError::InvalidTokenAccount,
+    )]
+    pub vault: UncheckedAccount<'info>,
+
+    /// Mint of the vault, validated in `vault`
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: player token account, or the player's wallet for native SOL casinos; validated in `vault`
+    #[account(mut)]
+    pub player_token_account: UncheckedAccount<'info>,
+
+    /// CHECK: Switchboard aggregator or `PriceCache` for the casino token, valuing the
+    /// win in USD stats when USD limits are configured; validated in `oracle`
+    pub price_feed: Option<UncheckedAccount<'info>>,
+
+    pub player: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ClaimJackpot>) -> Result<()> {
+    let game = &ctx.accounts.game;
+    require!(
+        matches!(game.status, GameStatus::Resolved | GameStatus::Claimed),
+        CasinoError::CannotClaimWinnings
+    );
+    require!(!game.jackpot_claimed, CasinoError::BonusAlreadyClaimed);
+
+    // The win was priced and locked when resolution triggered it
+    let payout = game.jackpot_payout;
+    require!(payout > 0, CasinoError::CannotClaimWinnings);
+
+    vault::pay_out(
+        &ctx.accounts.casino,
+        ctx.accounts.casino.vault_ref(),
+        &ctx.accounts.vault,
+        &ctx.accounts.mint,
+        &ctx.accounts.player_token_account,
+        &ctx.accounts.player.key(),
+        &ctx.accounts.token_program,
+        &ctx.accounts.system_program,
+        payout,
+    )?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let payout_usd = oracle::stats_usd_value(
+        &ctx.accounts.casino,
+        payout,
+        ctx.accounts.mint.decimals,
+        ctx.accounts.price_feed.as_deref(),
+    );
+    ctx.accounts.jackpot.record_payout(payout);
+    ctx.accounts.casino.record_jackpot_payout(payout, payout_usd);
+    ctx.accounts.game.jackpot_claimed = true;
+
+    emit!(JackpotWon {
+        jackpot: ctx.accounts.jackpot.key(),
+        casino: ctx.accounts.casino.key(),
+        game: ctx.accounts.game.key(),
+        player: ctx.accounts.player.key(),
+        payout,
+        payout_usd,
+        timestamp: now,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenInterface};
+
+use crate::errors::CasinoError;
+use crate::state::*;
+use crate::vault;
+
+#[derive(Accounts)]
+pub struct ClaimRouletteBet<'info> {
+    #[account(mut)]
+    pub casino: Account<'info, Casino>,
+
+    #[account(
+        mut,
+        has_one = casino,
+        seeds = [ROULETTE_ROUND_SEED, casino.key().as_ref(), &round.round_id.to_le_bytes()],
+        bump = round.bump
+    )]
+    pub round: Account<'info, RouletteRound>,
+
+    #[account(
+        mut,
+        has_one = round,
+        has_one = player,
+        seeds = [ROULETTE_BET_SEED, round.key().as_ref(), &bet.bet_index.to_le_bytes()],
+        bump = bet.bump
+    )]
+    pub bet: Account<'info, RouletteBet>,
+
+    /// CHECK: token account or native SOL vault PDA, validated against `casino.vault`
+    #[account(
+        mut,
+        address = casino.vault @ CasinoError::InvalidTokenAccount,
+    )]
+    pub vault: UncheckedAccount<'info>,
+
+    /// Mint of the vault, validated in `vault`
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: player token account, or the player's wallet for native SOL casinos; validated in `vault`
+    #[account(mut)]
+    pub player_token_account: UncheckedAccount<'info>,
+
+    pub player: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ClaimRouletteBet>) -> Result<()> {
+    let result = ctx.accounts.round.result.ok_or(CasinoError::RandomnessNotAvailable)?;
+    require!(!ctx.accounts.bet.claimed, CasinoError::GameAlreadyClaimed);
+
+    let payout = ctx.accounts.bet.winnings(result)?;
+    require!(payout > 0, CasinoError::CannotClaimWinnings);
+
+    vault::pay_out(
+        &ctx.accounts.casino,
+        ctx.accounts.casino.vault_ref(),
+        &ctx.accounts.vault,
+        &ctx.accounts.mint,
+        &ctx.accounts.player_token_account,
+        &ctx.accounts.player.key(),
+        &ctx.accounts.token_program,
+        &ctx.accounts.system_program,
+        payout,
+    )?;
+
+    ctx.accounts.casino.release_liability(payout);
+    let round = &mut ctx.accounts.round;
+    round.reserved_liability = round.reserved_liability.saturating_sub(payout);
+
+    let bet = &mut ctx.accounts.bet;
+    bet.payout = payout;
+    bet.claimed = true;
+
+    Ok(())
+}
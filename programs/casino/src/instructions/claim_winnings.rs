@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenInterface};
+
+use crate::events::WinningsClaimed;
+use crate::state::*;
+use crate::vault;
+
+#[derive(Accounts)]
+pub struct ClaimWinnings<'info> {
+    #[account(mut)]
+    pub casino: Account<'info, Casino>,
+
+    /// Required when the game was played in a non-primary mint
+    #[account(
+        mut,
+        has_one = casino,
+        seeds = [CASINO_MINT_SEED, casino.key().as_ref(), casino_mint.mint.as_ref()],
+        bump = casino_mint.bump
+    )]
+    pub casino_mint: Option<Account<'info, CasinoMint>>,
+
+    #[account(
+        mut,
+        has_one = casino,
+        has_one = player,
+    )]
+    pub game: Account<'info, Game>,
+
+    /// CHECK: token account or native SOL vault PDA, validated in `vault`
+    #[account(mut)]
+    pub vault: UncheckedAccount<'info>,
+
+    /// Mint of the vault, validated in `vault`
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: player token account, or the player's wallet for native SOL casinos; validated in `vault`
+    #[account(mut)]
+    pub player_token_account: UncheckedAccount<'info>,
+
+    pub player: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ClaimWinnings>) -> Result<()> {
+    let game = &mut ctx.accounts.game;
+    let payout = game.claim_winnings()?;
+    let vault_ref = game.vault_ref(&ctx.accounts.casino, ctx.accounts.casino_mint.as_deref())?;
+
+    let received = vault::pay_out(
+        &ctx.accounts.casino,
+        vault_ref,
+        &ctx.accounts.vault,
+        &ctx.accounts.mint,
+        &ctx.accounts.player_token_account,
+        &ctx.accounts.player.key(),
+        &ctx.accounts.token_program,
+        &ctx.accounts.system_program,
+        payout,
+    )?;
+
+    match ctx.accounts.casino_mint.as_mut() {
+        Some(casino_mint) => casino_mint.release_liability(payout),
+        None => ctx.accounts.casino.release_liability(payout),
+    }
+
+    emit!(WinningsClaimed {
+        game: ctx.accounts.game.key(),
+        casino: ctx.accounts.casino.key(),
+        player: ctx.accounts.player.key(),
+        amount: received,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
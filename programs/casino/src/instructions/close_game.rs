@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::CasinoError;
+use crate::events::GameClosed;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct CloseGame<'info> {
+    #[account(
+        mut,
+        has_one = player,
+        close = player
+    )]
+    pub game: Account<'info, Game>,
+
+    /// CHECK: original payer of the game account, validated against `game.player`
+    #[account(mut)]
+    pub player: UncheckedAccount<'info>,
+
+    /// Player or permissionless crank
+    pub closer: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<CloseGame>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let game = &ctx.accounts.game;
+    require!(game.is_settled(), CasinoError::InvalidStateTransition);
+    require!(
+        game.can_be_closed_by(&ctx.accounts.closer.key(), now),
+        CasinoError::CooldownPeriodActive
+    );
+
+    // Preserve the essential result before the account is reclaimed
+    let result = game.result.as_ref();
+    emit!(GameClosed {
+        game: game.key(),
+        casino: game.casino,
+        player: game.player,
+        game_type: game.game_type,
+        status: game.status,
+        bet_amount: game.bet_amount,
+        outcome: result.map(|r| r.outcome.clone()).unwrap_or_default(),
+        multiplier: result.map_or(0, |r| r.multiplier),
+        payout: result.map_or(0, |r| r.payout),
+        server_seed: game.provable_fair.server_seed.clone(),
+        created_at: game.created_at,
+        resolved_at: game.resolved_at,
+        closed_by: ctx.accounts.closer.key(),
+        timestamp: now,
+    });
+
+    Ok(())
+}
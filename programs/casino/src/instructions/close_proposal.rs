@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::CasinoError;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct CloseProposal<'info> {
+    #[account(
+        seeds = [MULTISIG_SEED, multisig.casino.as_ref()],
+        bump = multisig.bump
+    )]
+    pub multisig: Account<'info, Multisig>,
+
+    #[account(
+        mut,
+        has_one = multisig,
+        has_one = proposer,
+        close = proposer,
+        seeds = [PROPOSAL_SEED, multisig.key().as_ref(), &proposal.proposal_id.to_le_bytes()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    /// CHECK: receives the proposal rent, validated against `proposal.proposer`
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<CloseProposal>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+
+    // Only proposals that can no longer execute are closed
+    require!(
+        !ctx.accounts.proposal.is_open(&ctx.accounts.multisig, now),
+        CasinoError::OperationNotAllowed
+    );
+
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::CasinoError;
+use crate::events::ServerSeedCommitted;
+use crate::state::*;
+
+#[derive(Accounts)]
+#[instruction(hash: [u8; 32])]
+pub struct CommitServerSeed<'info> {
+    pub casino: Account<'info, Casino>,
+
+    #[account(
+        seeds = [ROLES_SEED, casino.key().as_ref()],
+        bump = roles.bump,
+        constraint = roles.has_role(Role::Resolver, &authority.key()) @ CasinoError::InsufficientPermissions,
+    )]
+    pub roles: Account<'info, CasinoRoles>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = SeedCommitment::LEN,
+        seeds = [SEED_COMMITMENT_SEED, casino.key().as_ref(), hash.as_ref()],
+        bump
+    )]
+    pub seed_commitment: Account<'info, SeedCommitment>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<CommitServerSeed>, hash: [u8; 32]) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let seed_commitment = &mut ctx.accounts.seed_commitment;
+    seed_commitment.casino = ctx.accounts.casino.key();
+    seed_commitment.hash = hash;
+    seed_commitment.committed_by = ctx.accounts.authority.key();
+    seed_commitment.committed_at = now;
+    seed_commitment.game = None;
+    seed_commitment.bump = ctx.bumps.seed_commitment;
+
+    emit!(ServerSeedCommitted {
+        casino: seed_commitment.casino,
+        seed_commitment: seed_commitment.key(),
+        server_seed_hash: hex::encode(hash),
+        timestamp: now,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,117 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+use crate::errors::CasinoError;
+use crate::oracle;
+use crate::state::*;
+use crate::vault;
+
+#[derive(Accounts)]
+#[instruction(duel_id: u64)]
+pub struct CreateDuel<'info> {
+    #[account(
+        has_one = token_mint @ CasinoError::InvalidTokenMint,
+    )]
+    pub casino: Account<'info, Casino>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = Duel::LEN,
+        seeds = [DUEL_SEED, casino.key().as_ref(), creator.key().as_ref(), &duel_id.to_le_bytes()],
+        bump
+    )]
+    pub duel: Account<'info, Duel>,
+
+    #[account(
+        init,
+        payer = creator,
+        seeds = [DUEL_ESCROW_SEED, duel.key().as_ref()],
+        bump,
+        token::mint = token_mint,
+        token::authority = duel,
+        token::token_program = token_program,
+    )]
+    pub escrow: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        constraint = creator_token_account.owner == creator.key() @ CasinoError::InvalidTokenAccount,
+        constraint = creator_token_account.mint == token_mint.key() @ CasinoError::InvalidTokenMint,
+    )]
+    pub creator_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: the player's ban PDA, which must not exist
+    #[account(
+        seeds = [PLAYER_BAN_SEED, casino.key().as_ref(), creator.key().as_ref()],
+        bump
+    )]
+    pub player_ban: UncheckedAccount<'info>,
+
+    /// CHECK: Switchboard aggregator or `PriceCache` for the casino token, required when
+    /// USD limits are configured; validated in `oracle`
+    pub price_feed: Option<UncheckedAccount<'info>>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler(
+    ctx: Context<CreateDuel>,
+    duel_id: u64,
+    stake: u64,
+    side: u8,
+    seed_hash: String,
+) -> Result<()> {
+    let casino = &ctx.accounts.casino;
+    casino.validate_accepting_bets()?;
+    PlayerBan::require_not_banned(&ctx.accounts.player_ban)?;
+    let stake_usd =
+        oracle::validate_bet_amount(casino, stake, ctx.accounts.token_mint.decimals, ctx.accounts.price_feed.as_deref())?;
+    require!(side <= 1, CasinoError::InvalidPrediction);
+    require!(seed_hash.len() == 64, CasinoError::InvalidClientSeed);
+    vault::validate_mint(&ctx.accounts.token_mint.to_account_info())?;
+
+    token_interface::transfer_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.creator_token_account.to_account_info(),
+                mint: ctx.accounts.token_mint.to_account_info(),
+                to: ctx.accounts.escrow.to_account_info(),
+                authority: ctx.accounts.creator.to_account_info(),
+            },
+        ),
+        stake,
+        ctx.accounts.token_mint.decimals,
+    )?;
+
+    let duel = &mut ctx.accounts.duel;
+    duel.casino = casino.key();
+    duel.creator = ctx.accounts.creator.key();
+    duel.opponent = None;
+    duel.duel_id = duel_id;
+    duel.stake = stake;
+    duel.creator_side = side;
+    duel.creator_seed_hash = seed_hash;
+    duel.opponent_seed_hash = None;
+    duel.creator_seed = None;
+    duel.opponent_seed = None;
+    duel.status = DuelStatus::Open;
+    duel.winner = None;
+    duel.payout = 0;
+    duel.treasury_fee_taken = 0;
+    duel.stake_usd = stake_usd;
+    duel.created_at = Clock::get()?.unix_timestamp;
+    duel.matched_at = None;
+    duel.settled_at = None;
+    duel.bump = ctx.bumps.duel;
+
+    Ok(())
+}
@@ -0,0 +1,215 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenInterface};
+
+use crate::errors::CasinoError;
+use crate::events::GameCreated;
+use crate::oracle;
+use crate::state::*;
+use crate::vault;
+
+#[derive(Accounts)]
+#[instruction(session_id: u64)]
+pub struct CreateGame<'info> {
+    #[account(mut)]
+    pub casino: Account<'info, Casino>,
+
+    /// Required when betting in a non-primary mint
+    #[account(
+        mut,
+        has_one = casino,
+        seeds = [CASINO_MINT_SEED, casino.key().as_ref(), casino_mint.mint.as_ref()],
+        bump = casino_mint.bump
+    )]
+    pub casino_mint: Option<Account<'info, CasinoMint>>,
+
+    /// Server seed hash committed by the casino, bound to this game
+    #[account(
+        mut,
+        has_one = casino,
+        seeds = [SEED_COMMITMENT_SEED, casino.key().as_ref(), seed_commitment.hash.as_ref()],
+        bump = seed_commitment.bump
+    )]
+    pub seed_commitment: Account<'info, SeedCommitment>,
+
+    #[account(
+        init,
+        payer = player,
+        space = Game::LEN,
+        seeds = [GAME_SEED, casino.key().as_ref(), player.key().as_ref(), &session_id.to_le_bytes()],
+        bump
+    )]
+    pub game: Account<'info, Game>,
+
+    /// CHECK: token account or native SOL vault PDA, validated in `vault`
+    #[account(mut)]
+    pub vault: UncheckedAccount<'info>,
+
+    /// Mint of the vault, validated in `vault`
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: player token account, or the player's wallet for native SOL casinos; validated in `vault`
+    #[account(mut)]
+    pub player_token_account: UncheckedAccount<'info>,
+
+    /// CHECK: the player's ban PDA, which must not exist
+    #[account(
+        seeds = [PLAYER_BAN_SEED, casino.key().as_ref(), player.key().as_ref()],
+        bump
+    )]
+    pub player_ban: UncheckedAccount<'info>,
+
+    /// CHECK: the casino's jackpot PDA, loaded in `Jackpot::load` when it exists
+    #[account(
+        mut,
+        seeds = [JACKPOT_SEED, casino.key().as_ref()],
+        bump
+    )]
+    pub jackpot: UncheckedAccount<'info>,
+
+    /// CHECK: Switchboard aggregator or `PriceCache` for the casino token, required when
+    /// USD limits are configured; validated in `oracle`
+    pub price_feed: Option<UncheckedAccount<'info>>,
+
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<CreateGame>,
+    session_id: u64,
+    game_type: GameType,
+    bet_amount: u64,
+    prediction: Vec<u8>,
+    client_seed: String,
+    side_bets: Vec<SideBet>,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let casino = &ctx.accounts.casino;
+    casino.validate_accepting_bets()?;
+    PlayerBan::require_not_banned(&ctx.accounts.player_ban)?;
+    require!(
+        !client_seed.is_empty() && client_seed.len() <= MAX_CLIENT_SEED_LEN,
+        CasinoError::InvalidClientSeed
+    );
+
+    // The edge and fee in force now are the ones the game settles with
+    let (vault_ref, bet_usd, house_edge, treasury_fee) = match ctx.accounts.casino_mint.as_deref() {
+        Some(casino_mint) => {
+            casino_mint.validate_bet_amount(bet_amount)?;
+            (
+                casino_mint.vault_ref(),
+                None,
+                casino_mint.effective_house_edge(casino),
+                casino_mint.effective_treasury_fee(casino),
+            )
+        },
+        None => {
+            let mint = &ctx.accounts.mint;
+            let bet_usd = oracle::validate_bet_amount(casino, bet_amount, mint.decimals, ctx.accounts.price_feed.as_deref())?;
+            (casino.vault_ref(), bet_usd, casino.house_edge, casino.treasury_fee)
+        },
+    };
+
+    // Token-2022 transfer fees on the whole wager come out of the main bet, so the
+    // game is sized on what the vault receives
+    let side_stakes = side_bets
+        .iter()
+        .try_fold(0u64, |total, side_bet| total.checked_add(side_bet.amount))
+        .ok_or(CasinoError::ArithmeticOverflow)?;
+    let wager = bet_amount.checked_add(side_stakes).ok_or(CasinoError::ArithmeticOverflow)?;
+    let fee = vault::transfer_fee(&ctx.accounts.mint.to_account_info(), wager)?;
+    let credited_bet = bet_amount.checked_sub(fee).ok_or(CasinoError::BetTooSmall)?;
+    require!(credited_bet > 0, CasinoError::BetTooSmall);
+
+    let server_seed_hash = ctx.accounts.seed_commitment.consume(ctx.accounts.game.key())?;
+
+    let game = &mut ctx.accounts.game;
+    game.player = ctx.accounts.player.key();
+    game.casino = ctx.accounts.casino.key();
+    game.mint = vault_ref.mint;
+    game.game_type = game_type;
+    game.bet_amount = credited_bet;
+    game.prediction = prediction;
+    game.side_bets = side_bets;
+    game.result = None;
+    game.provable_fair = ProvableFairData {
+        server_seed_hash: server_seed_hash.clone(),
+        client_seed,
+        nonce: session_id,
+        server_seed: None,
+    };
+    game.status = GameStatus::Active;
+    game.created_at = now;
+    game.resolved_at = None;
+    game.claimed_at = None;
+    game.expires_at = now + MAX_GAME_DURATION;
+    game.session_id = session_id;
+    game.jackpot_contribution = 0;
+    game.jackpot_payout = 0;
+    game.jackpot_claimed = false;
+    game.house_edge = house_edge;
+    game.treasury_fee = treasury_fee;
+    game.bump = ctx.bumps.game;
+    game.validate_prediction()?;
+    game.validate_side_bets()?;
+
+    // Every game, Limbo targets and side bets included, is bounded by max payout and
+    // free bankroll
+    let potential_payout = game.potential_payout()?;
+    let max_multiplier = game.max_multiplier()?;
+    let vault_balance = vault::balance(vault_ref, &ctx.accounts.vault)?;
+    match ctx.accounts.casino_mint.as_deref() {
+        Some(casino_mint) => {
+            casino_mint.validate_bet_risk(&ctx.accounts.casino, credited_bet, max_multiplier, vault_balance)?;
+            casino_mint.validate_potential_payout(potential_payout, vault_balance)?;
+        },
+        None => {
+            let casino = &ctx.accounts.casino;
+            casino.validate_bet_risk(credited_bet, max_multiplier, vault_balance)?;
+            casino.validate_potential_payout(potential_payout, vault_balance)?;
+        },
+    }
+
+    vault::deposit(
+        vault_ref,
+        &ctx.accounts.vault,
+        &ctx.accounts.mint,
+        &ctx.accounts.player_token_account,
+        &ctx.accounts.player,
+        &ctx.accounts.token_program,
+        &ctx.accounts.system_program,
+        wager,
+    )?;
+
+    match ctx.accounts.casino_mint.as_mut() {
+        Some(casino_mint) => casino_mint.reserve_liability(potential_payout)?,
+        None => {
+            ctx.accounts.casino.reserve_liability(potential_payout)?;
+            ctx.accounts.casino.record_usd_volume(bet_usd);
+
+            // Only primary-mint games feed, and so can win, the jackpot
+            if let Some(mut jackpot) = Jackpot::load(&ctx.accounts.jackpot)? {
+                let contribution = jackpot.contribute(ctx.accounts.game.total_wager()?)?;
+                jackpot.store(&ctx.accounts.jackpot)?;
+                ctx.accounts.casino.record_jackpot_contribution(contribution)?;
+                ctx.accounts.game.jackpot_contribution = contribution;
+            }
+        },
+    }
+
+    emit!(GameCreated {
+        game: ctx.accounts.game.key(),
+        casino: ctx.accounts.casino.key(),
+        player: ctx.accounts.player.key(),
+        game_type,
+        bet_amount: credited_bet,
+        session_id,
+        server_seed_hash,
+        timestamp: now,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::CasinoError;
+use crate::events::GovernanceProposalCreated;
+use crate::state::*;
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct CreateGovernanceProposal<'info> {
+    pub casino: Account<'info, Casino>,
+
+    #[account(
+        init,
+        payer = proposer,
+        space = GovernanceProposal::LEN,
+        seeds = [GOVERNANCE_PROPOSAL_SEED, casino.key().as_ref(), proposer.key().as_ref(), &proposal_id.to_le_bytes()],
+        bump
+    )]
+    pub proposal: Account<'info, GovernanceProposal>,
+
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<CreateGovernanceProposal>,
+    proposal_id: u64,
+    update: ParameterUpdate,
+    voting_period: i64,
+) -> Result<()> {
+    let casino = &ctx.accounts.casino;
+    let governance = casino.governance.ok_or(CasinoError::OperationNotAllowed)?;
+    require!(
+        voting_period >= governance.min_voting_period && voting_period <= MAX_VOTING_PERIOD,
+        CasinoError::InvalidTimestamp
+    );
+    update.validate()?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.casino = casino.key();
+    proposal.proposer = ctx.accounts.proposer.key();
+    proposal.proposal_id = proposal_id;
+    proposal.update = update;
+    proposal.votes_for = 0;
+    proposal.votes_against = 0;
+    proposal.status = GovernanceProposalStatus::Voting;
+    proposal.created_at = now;
+    proposal.voting_ends_at = now + voting_period;
+    proposal.finalized_at = None;
+    proposal.bump = ctx.bumps.proposal;
+
+    emit!(GovernanceProposalCreated {
+        casino: proposal.casino,
+        proposal: proposal.key(),
+        proposer: proposal.proposer,
+        update,
+        voting_ends_at: proposal.voting_ends_at,
+        timestamp: now,
+    });
+
+    Ok(())
+}
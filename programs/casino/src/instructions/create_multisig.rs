@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::CasinoError;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct CreateMultisig<'info> {
+    #[account(
+        has_one = authority @ CasinoError::Unauthorized,
+    )]
+    pub casino: Account<'info, Casino>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = Multisig::LEN,
+        seeds = [MULTISIG_SEED, casino.key().as_ref()],
+        bump
+    )]
+    pub multisig: Account<'info, Multisig>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<CreateMultisig>, signers: Vec<Pubkey>, threshold: u8) -> Result<()> {
+    Multisig::validate_signers(&signers, threshold)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let multisig = &mut ctx.accounts.multisig;
+    multisig.casino = ctx.accounts.casino.key();
+    multisig.signers = signers;
+    multisig.threshold = threshold;
+    multisig.version = 0;
+    multisig.proposal_count = 0;
+    multisig.created_at = now;
+    multisig.updated_at = now;
+    multisig.bump = ctx.bumps.multisig;
+
+    Ok(())
+}
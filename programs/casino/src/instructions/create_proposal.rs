@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::CasinoError;
+use crate::events::ProposalCreated;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct CreateProposal<'info> {
+    #[account(
+        mut,
+        seeds = [MULTISIG_SEED, multisig.casino.as_ref()],
+        bump = multisig.bump
+    )]
+    pub multisig: Account<'info, Multisig>,
+
+    #[account(
+        init,
+        payer = proposer,
+        space = Proposal::LEN,
+        seeds = [PROPOSAL_SEED, multisig.key().as_ref(), &multisig.proposal_count.to_le_bytes()],
+        bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<CreateProposal>, action: ProposalAction, ttl: i64) -> Result<()> {
+    require!(ttl > 0 && ttl <= MAX_PROPOSAL_TTL, CasinoError::InvalidTimestamp);
+    action.validate()?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let multisig = &mut ctx.accounts.multisig;
+    let signer_index = multisig.signer_index(&ctx.accounts.proposer.key())?;
+
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.multisig = multisig.key();
+    proposal.proposal_id = multisig.proposal_count;
+    proposal.proposer = ctx.accounts.proposer.key();
+    proposal.action = action;
+    proposal.version = multisig.version;
+    proposal.approvals = 0;
+    proposal.status = ProposalStatus::Active;
+    proposal.created_at = now;
+    proposal.expires_at = now + ttl;
+    proposal.executed_at = None;
+    proposal.bump = ctx.bumps.proposal;
+
+    // Creating a proposal counts as the proposer's approval
+    proposal.approve(signer_index)?;
+
+    multisig.proposal_count = multisig.proposal_count
+        .checked_add(1)
+        .ok_or(CasinoError::ArithmeticOverflow)?;
+
+    emit!(ProposalCreated {
+        multisig: multisig.key(),
+        proposal: proposal.key(),
+        proposer: proposal.proposer,
+        action: proposal.action.clone(),
+        expires_at: proposal.expires_at,
+        timestamp: now,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, MintTo, TokenAccount, TokenInterface};
+
+use crate::errors::CasinoError;
+use crate::events::LiquidityDeposited;
+use crate::state::*;
+use crate::vault;
+
+#[derive(Accounts)]
+pub struct DepositLiquidity<'info> {
+    pub casino: Account<'info, Casino>,
+
+    #[account(
+        mut,
+        has_one = casino,
+        has_one = share_mint,
+        seeds = [LIQUIDITY_POOL_SEED, casino.key().as_ref()],
+        bump = liquidity_pool.bump
+    )]
+    pub liquidity_pool: Account<'info, LiquidityPool>,
+
+    #[account(mut)]
+    pub share_mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: token account or native SOL vault PDA, validated against `casino.vault`
+    #[account(
+        mut,
+        address = casino.vault @ CasinoError::InvalidTokenAccount,
+    )]
+    pub vault: UncheckedAccount<'info>,
+
+    /// Mint of the vault, validated in `vault`
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: provider token account, or the provider's wallet for native SOL casinos; validated in `vault`
+    #[account(mut)]
+    pub provider_token_account: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        token::mint = share_mint,
+        token::authority = provider,
+    )]
+    pub provider_share_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub provider: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<DepositLiquidity>, amount: u64) -> Result<()> {
+    let casino = &ctx.accounts.casino;
+    require!(casino.is_active, CasinoError::CasinoNotActive);
+    require!(amount > 0, CasinoError::InvalidInstructionData);
+
+    let vault_balance = vault::balance(casino.vault_ref(), &ctx.accounts.vault)?;
+    let pool_value = casino.free_vault_balance(vault_balance);
+
+    let credited = vault::deposit(
+        casino.vault_ref(),
+        &ctx.accounts.vault,
+        &ctx.accounts.mint,
+        &ctx.accounts.provider_token_account,
+        &ctx.accounts.provider,
+        &ctx.accounts.token_program,
+        &ctx.accounts.system_program,
+        amount,
+    )?;
+
+    let shares = LiquidityPool::shares_for_deposit(credited, pool_value, ctx.accounts.share_mint.supply)?;
+    require!(shares > 0, CasinoError::InvalidInstructionData);
+    ctx.accounts.liquidity_pool.record_deposit(credited)?;
+
+    let seeds = &[CASINO_SEED, casino.token_mint.as_ref(), &[casino.bump]];
+    let signer = &[&seeds[..]];
+
+    token_interface::mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.share_mint.to_account_info(),
+                to: ctx.accounts.provider_share_account.to_account_info(),
+                authority: ctx.accounts.casino.to_account_info(),
+            },
+            signer,
+        ),
+        shares,
+    )?;
+
+    emit!(LiquidityDeposited {
+        pool: ctx.accounts.liquidity_pool.key(),
+        casino: ctx.accounts.casino.key(),
+        provider: ctx.accounts.provider.key(),
+        amount: credited,
+        shares,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::CasinoError;
+use crate::events::CasinoPaused;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct EmergencyPause<'info> {
+    #[account(mut)]
+    pub casino: Account<'info, Casino>,
+
+    #[account(
+        seeds = [ROLES_SEED, casino.key().as_ref()],
+        bump = roles.bump,
+        constraint = roles.has_role(Role::Pauser, &authority.key()) @ CasinoError::InsufficientPermissions,
+    )]
+    pub roles: Account<'info, CasinoRoles>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<EmergencyPause>, reason: PauseReason) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let casino = &mut ctx.accounts.casino;
+    require!(!casino.is_paused, CasinoError::InvalidStateTransition);
+    casino.pause(ctx.accounts.authority.key(), reason, now);
+
+    emit!(CasinoPaused {
+        casino: casino.key(),
+        authority: ctx.accounts.authority.key(),
+        reason,
+        timestamp: now,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::CasinoError;
+use crate::events::GovernanceProposalFinalized;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct ExecuteGovernanceProposal<'info> {
+    #[account(mut)]
+    pub casino: Account<'info, Casino>,
+
+    #[account(
+        mut,
+        has_one = casino,
+        seeds = [GOVERNANCE_PROPOSAL_SEED, casino.key().as_ref(), proposal.proposer.as_ref(), &proposal.proposal_id.to_le_bytes()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, GovernanceProposal>,
+}
+
+pub fn handler(ctx: Context<ExecuteGovernanceProposal>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let casino = &mut ctx.accounts.casino;
+    let proposal = &mut ctx.accounts.proposal;
+    require!(proposal.status == GovernanceProposalStatus::Voting, CasinoError::InvalidStateTransition);
+    require!(now >= proposal.voting_ends_at, CasinoError::OperationNotAllowed);
+
+    let governance = casino.governance.ok_or(CasinoError::OperationNotAllowed)?;
+    let expired = now > proposal.execution_deadline();
+    let passed = proposal.has_passed(&governance) && !expired;
+
+    // Increases still wait out the timelock, but the operator cannot cancel them
+    let queued = if passed {
+        proposal.status = GovernanceProposalStatus::Executed;
+        casino.update_parameters(&proposal.update, now)?
+    } else if expired {
+        proposal.status = GovernanceProposalStatus::Expired;
+        None
+    } else {
+        proposal.status = GovernanceProposalStatus::Defeated;
+        None
+    };
+    proposal.finalized_at = Some(now);
+
+    emit!(GovernanceProposalFinalized {
+        casino: casino.key(),
+        proposal: proposal.key(),
+        passed,
+        votes_for: proposal.votes_for,
+        votes_against: proposal.votes_against,
+        queued,
+        timestamp: now,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,138 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Burn, Mint, TokenAccount, TokenInterface};
+
+use crate::errors::CasinoError;
+use crate::events::LiquidityWithdrawn;
+use crate::state::*;
+use crate::vault;
+
+#[derive(Accounts)]
+pub struct ExecuteLiquidityWithdrawal<'info> {
+    pub casino: Account<'info, Casino>,
+
+    #[account(
+        mut,
+        has_one = casino,
+        has_one = share_mint,
+        seeds = [LIQUIDITY_POOL_SEED, casino.key().as_ref()],
+        bump = liquidity_pool.bump
+    )]
+    pub liquidity_pool: Account<'info, LiquidityPool>,
+
+    #[account(mut)]
+    pub share_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [LP_ESCROW_SEED, liquidity_pool.key().as_ref()],
+        bump,
+    )]
+    pub share_escrow: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        has_one = provider,
+        seeds = [LP_WITHDRAWAL_SEED, liquidity_pool.key().as_ref(), provider.key().as_ref()],
+        bump = withdrawal_request.bump
+    )]
+    pub withdrawal_request: Account<'info, WithdrawalRequest>,
+
+    /// CHECK: token account or native SOL vault PDA, validated against `casino.vault`
+    #[account(
+        mut,
+        address = casino.vault @ CasinoError::InvalidTokenAccount,
+    )]
+    pub vault: UncheckedAccount<'info>,
+
+    /// Mint of the vault, validated in `vault`
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: provider token account, or the provider's wallet for native SOL casinos; validated in `vault`
+    #[account(mut)]
+    pub provider_token_account: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub provider: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ExecuteLiquidityWithdrawal>) -> Result<()> {
+    let liquidity_pool = &ctx.accounts.liquidity_pool;
+    let request = &ctx.accounts.withdrawal_request;
+    request.validate_executable(liquidity_pool)?;
+
+    // Priced at the lower of the epoch snapshot and the current value, so losses
+    // since the boundary are shared with the LPs who stay rather than left to them
+    let casino = &ctx.accounts.casino;
+    let vault_balance = vault::balance(casino.vault_ref(), &ctx.accounts.vault)?;
+    let free_balance = casino.free_vault_balance(vault_balance);
+    let share_supply = ctx.accounts.share_mint.supply;
+    let owed = LiquidityPool::amount_for_shares(
+        request.shares,
+        liquidity_pool.epoch_pool_value,
+        liquidity_pool.epoch_share_supply,
+    )?
+    .min(LiquidityPool::amount_for_shares(request.shares, free_balance, share_supply)?);
+
+    // Never dip into funds reserved for open games; the remainder stays queued
+    let amount = owed.min(free_balance);
+    require!(amount > 0, CasinoError::InsufficientVaultFunds);
+
+    // Partial fills burn shares rounded up, so the shares left queued are never
+    // worth more than what is still owed on them
+    let shares = if amount == owed {
+        request.shares
+    } else {
+        let shares = (request.shares as u128 * amount as u128 + owed as u128 - 1) / owed as u128;
+        (shares as u64).min(request.shares)
+    };
+
+    let seeds = &[LIQUIDITY_POOL_SEED, liquidity_pool.casino.as_ref(), &[liquidity_pool.bump]];
+    let signer = &[&seeds[..]];
+
+    token_interface::burn(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.share_mint.to_account_info(),
+                from: ctx.accounts.share_escrow.to_account_info(),
+                authority: ctx.accounts.liquidity_pool.to_account_info(),
+            },
+            signer,
+        ),
+        shares,
+    )?;
+
+    vault::withdraw(
+        &ctx.accounts.casino,
+        ctx.accounts.casino.vault_ref(),
+        &ctx.accounts.vault,
+        &ctx.accounts.mint,
+        &ctx.accounts.provider_token_account,
+        &ctx.accounts.provider.key(),
+        &ctx.accounts.token_program,
+        &ctx.accounts.system_program,
+        amount,
+    )?;
+
+    ctx.accounts.liquidity_pool.record_withdrawal(shares, share_supply, amount)?;
+
+    let request = &mut ctx.accounts.withdrawal_request;
+    request.shares -= shares;
+    if request.shares == 0 {
+        request.close(ctx.accounts.provider.to_account_info())?;
+    }
+
+    emit!(LiquidityWithdrawn {
+        pool: ctx.accounts.liquidity_pool.key(),
+        casino: ctx.accounts.casino.key(),
+        provider: ctx.accounts.provider.key(),
+        amount,
+        shares,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
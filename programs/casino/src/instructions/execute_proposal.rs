@@ -0,0 +1,190 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+use crate::errors::CasinoError;
+use crate::events::{
+    AuthorityTransferAccepted, AuthorityTransferProposed, ConfigChangeQueued, ProposalExecuted, TreasuryWithdrawn,
+};
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct ExecuteProposal<'info> {
+    #[account(mut)]
+    pub casino: Account<'info, Casino>,
+
+    /// Required unless the action is on the casino authority or the signer set
+    #[account(
+        mut,
+        seeds = [ROLES_SEED, casino.key().as_ref()],
+        bump = roles.bump,
+    )]
+    pub roles: Option<Account<'info, CasinoRoles>>,
+
+    #[account(
+        mut,
+        has_one = casino,
+        seeds = [MULTISIG_SEED, casino.key().as_ref()],
+        bump = multisig.bump
+    )]
+    pub multisig: Account<'info, Multisig>,
+
+    #[account(
+        mut,
+        has_one = multisig,
+        seeds = [PROPOSAL_SEED, multisig.key().as_ref(), &proposal.proposal_id.to_le_bytes()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    /// Treasury token account, for withdrawals only
+    #[account(
+        mut,
+        address = casino.treasury @ CasinoError::InvalidTokenAccount,
+    )]
+    pub treasury: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        address = casino.token_mint @ CasinoError::InvalidTokenMint,
+    )]
+    pub token_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut)]
+    pub recipient: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+pub fn handler(ctx: Context<ExecuteProposal>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts.proposal.validate_executable(&ctx.accounts.multisig, now)?;
+
+    let action = ctx.accounts.proposal.action.clone();
+    match action.clone() {
+        ProposalAction::WithdrawTreasury { amount, recipient } => {
+            withdraw_treasury(&ctx, amount, recipient, now)?;
+        },
+        ProposalAction::ConfigChange { house_edge, treasury_fee, cancellation_fee, max_bet } => {
+            let roles = ctx.accounts.roles.as_deref().ok_or(CasinoError::AccountNotInitialized)?;
+            require!(
+                roles.has_role(Role::Operator, &ctx.accounts.multisig.key()),
+                CasinoError::InsufficientPermissions
+            );
+            let change = PendingConfigChange {
+                house_edge: house_edge.map(Some),
+                treasury_fee: treasury_fee.map(Some),
+                cancellation_fee,
+                max_bet,
+                queued_at: now,
+                effective_at: now + CONFIG_TIMELOCK,
+                by_governance: false,
+            };
+            ctx.accounts.casino.queue_config_change(change)?;
+
+            emit!(ConfigChangeQueued {
+                casino: ctx.accounts.casino.key(),
+                mint: None,
+                change,
+                timestamp: now,
+            });
+        },
+        ProposalAction::ProposeAuthority { role, proposed } => {
+            let casino = &mut ctx.accounts.casino;
+            require_keys_eq!(casino.authority, ctx.accounts.multisig.key(), CasinoError::InsufficientPermissions);
+            let current = match role {
+                Some(role) => ctx.accounts.roles
+                    .as_deref_mut()
+                    .ok_or(CasinoError::AccountNotInitialized)?
+                    .propose(role, proposed, now)?,
+                None => casino.propose_authority(proposed, now)?,
+            };
+
+            emit!(AuthorityTransferProposed {
+                casino: casino.key(),
+                role,
+                current,
+                proposed,
+                timestamp: now,
+            });
+        },
+        ProposalAction::AcceptAuthority { role } => {
+            let holder = ctx.accounts.multisig.key();
+            let casino = &mut ctx.accounts.casino;
+            let previous = match role {
+                Some(role) => ctx.accounts.roles
+                    .as_deref_mut()
+                    .ok_or(CasinoError::AccountNotInitialized)?
+                    .accept(role, holder, now)?,
+                None => casino.accept_authority(holder, now)?,
+            };
+
+            emit!(AuthorityTransferAccepted {
+                casino: casino.key(),
+                role,
+                previous,
+                holder,
+                timestamp: now,
+            });
+        },
+        ProposalAction::SetSigners { signers, threshold } => {
+            ctx.accounts.multisig.set_signers(signers, threshold, now)?;
+        },
+        // Carried out by `invoke_proposal`, which holds no casino state the call could overwrite
+        ProposalAction::Invoke { .. } => return err!(CasinoError::OperationNotAllowed),
+    }
+
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.status = ProposalStatus::Executed;
+    proposal.executed_at = Some(now);
+
+    emit!(ProposalExecuted {
+        multisig: proposal.multisig,
+        proposal: proposal.key(),
+        action,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+fn withdraw_treasury(ctx: &Context<ExecuteProposal>, amount: u64, recipient: Pubkey, now: i64) -> Result<()> {
+    let casino = &ctx.accounts.casino;
+    let roles = ctx.accounts.roles.as_deref().ok_or(CasinoError::AccountNotInitialized)?;
+    require!(
+        roles.has_role(Role::Treasurer, &ctx.accounts.multisig.key()),
+        CasinoError::InsufficientPermissions
+    );
+
+    let treasury = ctx.accounts.treasury.as_ref().ok_or(CasinoError::InvalidTokenAccount)?;
+    let token_mint = ctx.accounts.token_mint.as_ref().ok_or(CasinoError::InvalidTokenMint)?;
+    let to = ctx.accounts.recipient.as_ref().ok_or(CasinoError::InvalidTokenAccount)?;
+    let token_program = ctx.accounts.token_program.as_ref().ok_or(CasinoError::InvalidProgramId)?;
+    require_keys_eq!(to.key(), recipient, CasinoError::InvalidTokenAccount);
+
+    let seeds = &[CASINO_SEED, casino.token_mint.as_ref(), &[casino.bump]];
+    let signer = &[&seeds[..]];
+
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            TransferChecked {
+                from: treasury.to_account_info(),
+                mint: token_mint.to_account_info(),
+                to: to.to_account_info(),
+                authority: casino.to_account_info(),
+            },
+            signer,
+        ),
+        amount,
+        token_mint.decimals,
+    )?;
+
+    emit!(TreasuryWithdrawn {
+        casino: casino.key(),
+        mint: None,
+        authority: ctx.accounts.multisig.key(),
+        amount,
+        timestamp: now,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,137 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenInterface};
+
+use crate::errors::CasinoError;
+use crate::state::*;
+use crate::vault;
+
+#[derive(Accounts)]
+pub struct InitializeCasino<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = Casino::LEN,
+        seeds = [CASINO_SEED, token_mint.key().as_ref()],
+        bump
+    )]
+    pub casino: Account<'info, Casino>,
+
+    pub token_mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: vault PDA, created in `vault` as a token account or a rent-exempt system account
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, casino.key().as_ref()],
+        bump
+    )]
+    pub vault: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = CasinoRoles::LEN,
+        seeds = [ROLES_SEED, casino.key().as_ref()],
+        bump
+    )]
+    pub roles: Account<'info, CasinoRoles>,
+
+    /// CHECK: token account for `token_mint` collecting treasury fees, or a wallet for
+    /// native SOL casinos; validated in `vault`
+    pub treasury: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<InitializeCasino>,
+    house_edge: u16,
+    min_bet: u64,
+    max_bet: u64,
+    treasury_fee: u16,
+    governance: Option<GovernanceConfig>,
+) -> Result<()> {
+    require!(min_bet > 0 && min_bet <= max_bet, CasinoError::InvalidConfiguration);
+    if let Some(governance) = governance {
+        governance.validate()?;
+    }
+
+    vault::validate_mint(&ctx.accounts.token_mint.to_account_info())?;
+    vault::initialize(
+        &ctx.accounts.casino.to_account_info(),
+        &ctx.accounts.vault,
+        ctx.bumps.vault,
+        &ctx.accounts.token_mint,
+        &ctx.accounts.authority,
+        &ctx.accounts.token_program,
+        &ctx.accounts.system_program,
+    )?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let casino = &mut ctx.accounts.casino;
+    casino.authority = ctx.accounts.authority.key();
+    casino.pending_authority = None;
+    casino.token_mint = ctx.accounts.token_mint.key();
+    casino.treasury = ctx.accounts.treasury.key();
+    casino.vault = ctx.accounts.vault.key();
+    casino.house_edge = house_edge;
+    casino.min_bet = min_bet;
+    casino.max_bet = max_bet;
+    casino.treasury_fee = treasury_fee;
+    casino.max_payout = 0;
+    casino.reserved_liability = 0;
+    casino.treasury_owed = 0;
+    casino.cancellation_fee = MIN_CANCELLATION_FEE;
+    casino.pending_config = None;
+    casino.usd_limits = None;
+    casino.kelly_fraction = 0;
+    casino.circuit_breaker = CircuitBreaker::default();
+    casino.is_active = true;
+    casino.is_paused = false;
+    casino.resolve_only = false;
+    casino.unpause_delay = 0;
+    casino.pause_record = PauseRecord::default();
+    casino.maintenance_window = None;
+    casino.governance = governance;
+    casino.stats = CasinoStats {
+        total_games: 0,
+        total_volume: 0,
+        total_profit: 0,
+        total_payouts: 0,
+        active_players: 0,
+        house_edge_collected: 0,
+        treasury_fees_collected: 0,
+        jackpot_contributions: 0,
+        jackpot_payouts: 0,
+        games_expired: 0,
+        volume_usd: 0,
+        jackpot_payouts_usd: 0,
+    };
+    casino.created_at = now;
+    casino.updated_at = now;
+    casino.bump = ctx.bumps.casino;
+    casino.vault_bump = ctx.bumps.vault;
+
+    casino.validate_house_edge()?;
+    casino.validate_treasury_fee()?;
+    vault::validate_recipient(casino.vault_ref(), &ctx.accounts.treasury, &ctx.accounts.token_program)?;
+
+    // Every role starts out with the authority, which can hand each one on
+    let authority = ctx.accounts.authority.key();
+    let roles = &mut ctx.accounts.roles;
+    roles.casino = ctx.accounts.casino.key();
+    roles.operator = authority;
+    roles.resolver = authority;
+    roles.treasurer = authority;
+    roles.pauser = authority;
+    roles.compliance = authority;
+    roles.pending = [None; ROLE_COUNT];
+    roles.created_at = now;
+    roles.updated_at = now;
+    roles.bump = ctx.bumps.roles;
+
+    Ok(())
+}
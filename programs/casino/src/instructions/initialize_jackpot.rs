@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::CasinoError;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct InitializeJackpot<'info> {
+    pub casino: Account<'info, Casino>,
+
+    #[account(
+        seeds = [ROLES_SEED, casino.key().as_ref()],
+        bump = roles.bump,
+        constraint = roles.has_role(Role::Operator, &authority.key()) @ CasinoError::InsufficientPermissions,
+    )]
+    pub roles: Account<'info, CasinoRoles>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = Jackpot::LEN,
+        seeds = [JACKPOT_SEED, casino.key().as_ref()],
+        bump
+    )]
+    pub jackpot: Account<'info, Jackpot>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<InitializeJackpot>,
+    contribution_bps: u16,
+    payout_bps: u16,
+    trigger_odds: u32,
+) -> Result<()> {
+    let jackpot = &mut ctx.accounts.jackpot;
+    jackpot.casino = ctx.accounts.casino.key();
+    jackpot.contribution_bps = contribution_bps;
+    jackpot.payout_bps = payout_bps;
+    jackpot.trigger_odds = trigger_odds;
+    jackpot.balance = 0;
+    jackpot.total_contributed = 0;
+    jackpot.total_paid = 0;
+    jackpot.wins = 0;
+    jackpot.last_winner = None;
+    jackpot.last_payout = 0;
+    jackpot.last_won_at = None;
+    jackpot.bump = ctx.bumps.jackpot;
+
+    jackpot.validate_config()
+}
@@ -0,0 +1,129 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{self, Mint, MintTo, TokenAccount, TokenInterface};
+
+use crate::errors::CasinoError;
+use crate::state::*;
+use crate::vault;
+
+#[derive(Accounts)]
+pub struct InitializeLiquidityPool<'info> {
+    #[account(
+        has_one = authority @ CasinoError::Unauthorized,
+    )]
+    pub casino: Account<'info, Casino>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = LiquidityPool::LEN,
+        seeds = [LIQUIDITY_POOL_SEED, casino.key().as_ref()],
+        bump
+    )]
+    pub liquidity_pool: Account<'info, LiquidityPool>,
+
+    #[account(
+        init,
+        payer = payer,
+        seeds = [LP_MINT_SEED, casino.key().as_ref()],
+        bump,
+        mint::decimals = mint.decimals,
+        mint::authority = casino,
+        mint::token_program = token_program,
+    )]
+    pub share_mint: InterfaceAccount<'info, Mint>,
+
+    /// Holds shares of pending withdrawal requests
+    #[account(
+        init,
+        payer = payer,
+        seeds = [LP_ESCROW_SEED, liquidity_pool.key().as_ref()],
+        bump,
+        token::mint = share_mint,
+        token::authority = liquidity_pool,
+        token::token_program = token_program,
+    )]
+    pub share_escrow: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        address = casino.token_mint @ CasinoError::InvalidTokenMint,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: token account or native SOL vault PDA, validated against `casino.vault`
+    #[account(
+        address = casino.vault @ CasinoError::InvalidTokenAccount,
+    )]
+    pub vault: UncheckedAccount<'info>,
+
+    /// Receives shares for the bankroll already in the vault
+    #[account(
+        init,
+        payer = payer,
+        associated_token::mint = share_mint,
+        associated_token::authority = authority,
+        associated_token::token_program = token_program,
+    )]
+    pub authority_share_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub authority: Signer<'info>,
+
+    /// Pays rent for the new accounts, which a PDA authority such as the multisig cannot
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<InitializeLiquidityPool>,
+    deposit_cap: u64,
+    cooldown_period: i64,
+    epoch_duration: i64,
+) -> Result<()> {
+    let casino = &ctx.accounts.casino;
+    let vault_balance = vault::balance(casino.vault_ref(), &ctx.accounts.vault)?;
+    let seed_bankroll = casino.free_vault_balance(vault_balance);
+
+    // The authority's existing bankroll becomes the first LP position, so later
+    // depositors cannot redeem funds they did not put in
+    if seed_bankroll > 0 {
+        let seeds = &[CASINO_SEED, casino.token_mint.as_ref(), &[casino.bump]];
+        let signer = &[&seeds[..]];
+
+        token_interface::mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                MintTo {
+                    mint: ctx.accounts.share_mint.to_account_info(),
+                    to: ctx.accounts.authority_share_account.to_account_info(),
+                    authority: ctx.accounts.casino.to_account_info(),
+                },
+                signer,
+            ),
+            seed_bankroll,
+        )?;
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    let liquidity_pool = &mut ctx.accounts.liquidity_pool;
+    liquidity_pool.casino = ctx.accounts.casino.key();
+    liquidity_pool.share_mint = ctx.accounts.share_mint.key();
+    liquidity_pool.deposit_cap = deposit_cap;
+    liquidity_pool.total_deposits = seed_bankroll;
+    liquidity_pool.total_withdrawn = 0;
+    liquidity_pool.cooldown_period = cooldown_period;
+    liquidity_pool.epoch_duration = epoch_duration;
+    liquidity_pool.current_epoch = 0;
+    liquidity_pool.epoch_started_at = now;
+    liquidity_pool.epoch_pool_value = seed_bankroll;
+    liquidity_pool.epoch_share_supply = seed_bankroll;
+    liquidity_pool.pending_shares = 0;
+    liquidity_pool.created_at = now;
+    liquidity_pool.updated_at = now;
+    liquidity_pool.bump = ctx.bumps.liquidity_pool;
+
+    liquidity_pool.validate_config()
+}
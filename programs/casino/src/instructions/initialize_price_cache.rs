@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::errors::CasinoError;
+use crate::oracle;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct InitializePriceCache<'info> {
+    pub casino: Account<'info, Casino>,
+
+    #[account(
+        seeds = [ROLES_SEED, casino.key().as_ref()],
+        bump = roles.bump,
+        constraint = roles.has_role(Role::Operator, &authority.key()) @ CasinoError::InsufficientPermissions,
+    )]
+    pub roles: Account<'info, CasinoRoles>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = PriceCache::LEN,
+        seeds = [PRICE_CACHE_SEED, casino.key().as_ref(), mint.key().as_ref()],
+        bump
+    )]
+    pub price_cache: Account<'info, PriceCache>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// USD stablecoin the pool quotes against
+    pub quote_mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: pool base reserve token account, or the mock pool; validated in `oracle`
+    pub base_reserve: UncheckedAccount<'info>,
+
+    /// CHECK: pool quote reserve token account; validated in `oracle`
+    pub quote_reserve: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitializePriceCache>, pool_kind: PoolKind, window: i64) -> Result<()> {
+    require!(
+        pool_kind != PoolKind::Mock || cfg!(feature = "mock-pool"),
+        CasinoError::InvalidConfiguration
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let price_cache = &mut ctx.accounts.price_cache;
+    price_cache.casino = ctx.accounts.casino.key();
+    price_cache.mint = ctx.accounts.mint.key();
+    price_cache.quote_mint = ctx.accounts.quote_mint.key();
+    price_cache.pool_kind = pool_kind;
+    price_cache.base_reserve = ctx.accounts.base_reserve.key();
+    price_cache.quote_reserve = ctx.accounts.quote_reserve.key();
+    price_cache.base_decimals = ctx.accounts.mint.decimals;
+    price_cache.quote_decimals = ctx.accounts.quote_mint.decimals;
+    price_cache.window = window;
+    price_cache.spot_price = 0;
+    price_cache.cumulative_price = 0;
+    price_cache.last_updated = 0;
+    price_cache.observations = [PriceObservation::default(); TWAP_OBSERVATIONS];
+    price_cache.observation_index = 0;
+    price_cache.observation_count = 0;
+    price_cache.created_at = now;
+    price_cache.bump = ctx.bumps.price_cache;
+    price_cache.validate_config()?;
+
+    let (base_reserve, quote_reserve) = oracle::pool_reserves(
+        price_cache,
+        &ctx.accounts.base_reserve,
+        &ctx.accounts.quote_reserve,
+    )?;
+    let spot_price = price_cache.spot_price(base_reserve, quote_reserve)?;
+    price_cache.record(spot_price, now)
+}
@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke_signed;
+
+use crate::errors::CasinoError;
+use crate::events::ProposalExecuted;
+use crate::program::Casino as CasinoProgram;
+use crate::state::*;
+
+/// Carries out an `Invoke` proposal. The instruction's accounts are passed as
+/// remaining accounts, in order, and must match the proposal's `accounts_hash`.
+#[derive(Accounts)]
+pub struct InvokeProposal<'info> {
+    #[account(
+        seeds = [MULTISIG_SEED, multisig.casino.as_ref()],
+        bump = multisig.bump
+    )]
+    pub multisig: Account<'info, Multisig>,
+
+    #[account(
+        mut,
+        has_one = multisig,
+        seeds = [PROPOSAL_SEED, multisig.key().as_ref(), &proposal.proposal_id.to_le_bytes()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    pub casino_program: Program<'info, CasinoProgram>,
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, InvokeProposal<'info>>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let multisig = &ctx.accounts.multisig;
+    ctx.accounts.proposal.validate_executable(multisig, now)?;
+
+    let action = ctx.accounts.proposal.action.clone();
+    let (data, accounts_hash) = match &action {
+        ProposalAction::Invoke { data, accounts_hash } => (data.clone(), *accounts_hash),
+        _ => return err!(CasinoError::OperationNotAllowed),
+    };
+
+    let multisig_key = multisig.key();
+    let accounts: Vec<AccountMeta> = ctx
+        .remaining_accounts
+        .iter()
+        .map(|account| AccountMeta {
+            pubkey: account.key(),
+            is_signer: account.is_signer || account.key() == multisig_key,
+            is_writable: account.is_writable,
+        })
+        .collect();
+    require!(
+        Multisig::accounts_hash(&accounts) == accounts_hash,
+        CasinoError::InvalidInstructionData
+    );
+
+    // Written out before the call, so the invoked instruction sees it executed
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.status = ProposalStatus::Executed;
+    proposal.executed_at = Some(now);
+    proposal.exit(&crate::ID)?;
+
+    let mut account_infos = ctx.remaining_accounts.to_vec();
+    account_infos.push(ctx.accounts.casino_program.to_account_info());
+    let seeds = &[MULTISIG_SEED, multisig.casino.as_ref(), &[multisig.bump]];
+    invoke_signed(
+        &Instruction {
+            program_id: crate::ID,
+            accounts,
+            data,
+        },
+        &account_infos,
+        &[&seeds[..]],
+    )?;
+
+    emit!(ProposalExecuted {
+        multisig: multisig_key,
+        proposal: ctx.accounts.proposal.key(),
+        action,
+        timestamp: now,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+use crate::errors::CasinoError;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct JoinDuel<'info> {
+    pub casino: Account<'info, Casino>,
+
+    #[account(
+        mut,
+        has_one = casino,
+        seeds = [DUEL_SEED, casino.key().as_ref(), duel.creator.as_ref(), &duel.duel_id.to_le_bytes()],
+        bump = duel.bump
+    )]
+    pub duel: Account<'info, Duel>,
+
+    #[account(
+        mut,
+        seeds = [DUEL_ESCROW_SEED, duel.key().as_ref()],
+        bump,
+    )]
+    pub escrow: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        address = casino.token_mint @ CasinoError::InvalidTokenMint,
+    )]
+    pub token_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        constraint = opponent_token_account.owner == opponent.key() @ CasinoError::InvalidTokenAccount,
+        constraint = opponent_token_account.mint == casino.token_mint @ CasinoError::InvalidTokenMint,
+    )]
+    pub opponent_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: the player's ban PDA, which must not exist
+    #[account(
+        seeds = [PLAYER_BAN_SEED, casino.key().as_ref(), opponent.key().as_ref()],
+        bump
+    )]
+    pub player_ban: UncheckedAccount<'info>,
+
+    pub opponent: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handler(ctx: Context<JoinDuel>, seed_hash: String) -> Result<()> {
+    let casino = &ctx.accounts.casino;
+    casino.validate_accepting_bets()?;
+    PlayerBan::require_not_banned(&ctx.accounts.player_ban)?;
+
+    let duel = &ctx.accounts.duel;
+    require!(matches!(duel.status, DuelStatus::Open), CasinoError::DuelNotOpen);
+    require!(duel.creator != ctx.accounts.opponent.key(), CasinoError::CannotJoinOwnDuel);
+    require!(seed_hash.len() == 64, CasinoError::InvalidClientSeed);
+
+    token_interface::transfer_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.opponent_token_account.to_account_info(),
+                mint: ctx.accounts.token_mint.to_account_info(),
+                to: ctx.accounts.escrow.to_account_info(),
+                authority: ctx.accounts.opponent.to_account_info(),
+            },
+        ),
+        duel.stake,
+        ctx.accounts.token_mint.decimals,
+    )?;
+
+    let duel = &mut ctx.accounts.duel;
+    duel.opponent = Some(ctx.accounts.opponent.key());
+    duel.opponent_seed_hash = Some(seed_hash);
+    duel.status = DuelStatus::Matched;
+    duel.matched_at = Some(Clock::get()?.unix_timestamp);
+
+    Ok(())
+}
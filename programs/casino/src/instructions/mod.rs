@@ -0,0 +1,119 @@
+#![allow(ambiguous_glob_reexports)]
+
+pub mod initialize_casino;
+pub mod create_game;
+pub mod resolve_game;
+pub mod claim_winnings;
+pub mod update_casino_config;
+pub mod emergency_pause;
+pub mod withdraw_treasury;
+pub mod commit_server_seed;
+pub mod create_duel;
+pub mod join_duel;
+pub mod reveal_duel_seed;
+pub mod settle_duel;
+pub mod cancel_duel;
+pub mod open_roulette_round;
+pub mod place_roulette_bet;
+pub mod spin_roulette_round;
+pub mod claim_roulette_bet;
+pub mod refund_roulette_bet;
+pub mod initialize_jackpot;
+pub mod claim_jackpot;
+pub mod close_game;
+pub mod expire_game;
+pub mod cancel_game;
+pub mod add_casino_mint;
+pub mod update_casino_mint;
+pub mod set_usd_limits;
+pub mod initialize_price_cache;
+pub mod update_price_cache;
+pub mod initialize_liquidity_pool;
+pub mod update_liquidity_pool;
+pub mod deposit_liquidity;
+pub mod request_liquidity_withdrawal;
+pub mod cancel_liquidity_withdrawal;
+pub mod settle_liquidity_epoch;
+pub mod execute_liquidity_withdrawal;
+pub mod update_risk_limits;
+pub mod reset_circuit_breaker;
+pub mod unpause_casino;
+pub mod set_maintenance_window;
+pub mod propose_authority;
+pub mod accept_authority;
+pub mod cancel_authority_transfer;
+pub mod queue_config_change;
+pub mod cancel_config_change;
+pub mod apply_config_change;
+pub mod create_multisig;
+pub mod create_proposal;
+pub mod approve_proposal;
+pub mod execute_proposal;
+pub mod invoke_proposal;
+pub mod cancel_proposal;
+pub mod close_proposal;
+pub mod create_governance_proposal;
+pub mod cast_vote;
+pub mod execute_governance_proposal;
+pub mod withdraw_vote;
+pub mod ban_player;
+pub mod unban_player;
+
+pub use initialize_casino::*;
+pub use create_game::*;
+pub use resolve_game::*;
+pub use claim_winnings::*;
+pub use update_casino_config::*;
+pub use emergency_pause::*;
+pub use withdraw_treasury::*;
+pub use commit_server_seed::*;
+pub use create_duel::*;
+pub use join_duel::*;
+pub use reveal_duel_seed::*;
+pub use settle_duel::*;
+pub use cancel_duel::*;
+pub use open_roulette_round::*;
+pub use place_roulette_bet::*;
+pub use spin_roulette_round::*;
+pub use claim_roulette_bet::*;
+pub use refund_roulette_bet::*;
+pub use initialize_jackpot::*;
+pub use claim_jackpot::*;
+pub use close_game::*;
+pub use expire_game::*;
+pub use cancel_game::*;
+pub use add_casino_mint::*;
+pub use update_casino_mint::*;
+pub use set_usd_limits::*;
+pub use initialize_price_cache::*;
+pub use update_price_cache::*;
+pub use initialize_liquidity_pool::*;
+pub use update_liquidity_pool::*;
+pub use deposit_liquidity::*;
+pub use request_liquidity_withdrawal::*;
+pub use cancel_liquidity_withdrawal::*;
+pub use settle_liquidity_epoch::*;
+pub use execute_liquidity_withdrawal::*;
+pub use update_risk_limits::*;
+pub use reset_circuit_breaker::*;
+pub use unpause_casino::*;
+pub use set_maintenance_window::*;
+pub use propose_authority::*;
+pub use accept_authority::*;
+pub use cancel_authority_transfer::*;
+pub use queue_config_change::*;
+pub use cancel_config_change::*;
+pub use apply_config_change::*;
+pub use create_multisig::*;
+pub use create_proposal::*;
+pub use approve_proposal::*;
+pub use execute_proposal::*;
+pub use invoke_proposal::*;
+pub use cancel_proposal::*;
+pub use close_proposal::*;
+pub use create_governance_proposal::*;
+pub use cast_vote::*;
+pub use execute_governance_proposal::*;
+pub use withdraw_vote::*;
+pub use ban_player::*;
+pub use unban_player::*;
@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::CasinoError;
+use crate::state::*;
+
+#[derive(Accounts)]
+#[instruction(round_id: u64)]
+pub struct OpenRouletteRound<'info> {
+    pub casino: Account<'info, Casino>,
+
+    #[account(
+        seeds = [ROLES_SEED, casino.key().as_ref()],
+        bump = roles.bump,
+        constraint = roles.has_role(Role::Resolver, &authority.key()) @ CasinoError::InsufficientPermissions,
+    )]
+    pub roles: Account<'info, CasinoRoles>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = RouletteRound::LEN,
+        seeds = [ROULETTE_ROUND_SEED, casino.key().as_ref(), &round_id.to_le_bytes()],
+        bump
+    )]
+    pub round: Account<'info, RouletteRound>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<OpenRouletteRound>,
+    round_id: u64,
+    server_seed_hash: String,
+    betting_window: i64,
+) -> Result<()> {
+    let casino = &ctx.accounts.casino;
+    require!(casino.is_operational(), CasinoError::CasinoNotActive);
+    require!(server_seed_hash.len() == 64, CasinoError::InvalidServerSeed);
+    require!(
+        betting_window > 0 && betting_window <= MAX_ROULETTE_BETTING_WINDOW,
+        CasinoError::InvalidTimestamp
+    );
+
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp;
+    let round = &mut ctx.accounts.round;
+    round.casino = casino.key();
+    round.round_id = round_id;
+    round.server_seed_hash = server_seed_hash;
+    round.server_seed = None;
+    round.bet_entropy = [0u8; 32];
+    round.closes_at = now + betting_window;
+    // Betting also stops by slot, in case slots run faster than the target time
+    round.entropy_slot = clock.slot + betting_window as u64 * 1000 / SLOT_DURATION_MS;
+    round.slot_hash = None;
+    round.status = RouletteRoundStatus::Open;
+    round.result = None;
+    round.bet_count = 0;
+    round.total_staked = 0;
+    round.payouts_by_pocket = [0u64; ROULETTE_POCKETS];
+    round.reserved_liability = 0;
+    round.created_at = now;
+    round.spun_at = None;
+    round.bump = ctx.bumps.round;
+
+    Ok(())
+}
@@ -0,0 +1,122 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenInterface};
+
+use crate::errors::CasinoError;
+use crate::oracle;
+use crate::state::*;
+use crate::vault;
+
+#[derive(Accounts)]
+pub struct PlaceRouletteBet<'info> {
+    #[account(mut)]
+    pub casino: Account<'info, Casino>,
+
+    #[account(
+        mut,
+        has_one = casino,
+        seeds = [ROULETTE_ROUND_SEED, casino.key().as_ref(), &round.round_id.to_le_bytes()],
+        bump = round.bump
+    )]
+    pub round: Account<'info, RouletteRound>,
+
+    #[account(
+        init,
+        payer = player,
+        space = RouletteBet::LEN,
+        seeds = [ROULETTE_BET_SEED, round.key().as_ref(), &round.bet_count.to_le_bytes()],
+        bump
+    )]
+    pub bet: Account<'info, RouletteBet>,
+
+    /// CHECK: token account or native SOL vault PDA, validated against `casino.vault`
+    #[account(
+        mut,
+        address = casino.vault @ CasinoError::InvalidTokenAccount,
+    )]
+    pub vault: UncheckedAccount<'info>,
+
+    /// Mint of the vault, validated in `vault`
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: player token account, or the player's wallet for native SOL casinos; validated in `vault`
+    #[account(mut)]
+    pub player_token_account: UncheckedAccount<'info>,
+
+    /// CHECK: the player's ban PDA, which must not exist
+    #[account(
+        seeds = [PLAYER_BAN_SEED, casino.key().as_ref(), player.key().as_ref()],
+        bump
+    )]
+    pub player_ban: UncheckedAccount<'info>,
+
+    /// CHECK: Switchboard aggregator or `PriceCache` for the casino token, required when
+    /// USD limits are configured; validated in `oracle`
+    pub price_feed: Option<UncheckedAccount<'info>>,
+
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<PlaceRouletteBet>,
+    kind: RouletteBetKind,
+    selection: u8,
+    amount: u64,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp;
+    let casino = &ctx.accounts.casino;
+    casino.validate_accepting_bets()?;
+    PlayerBan::require_not_banned(&ctx.accounts.player_ban)?;
+    require!(ctx.accounts.round.is_betting_open(now, clock.slot), CasinoError::OperationNotAllowed);
+    let bet_usd = oracle::validate_bet_amount(casino, amount, ctx.accounts.mint.decimals, ctx.accounts.price_feed.as_deref())?;
+
+    // Token-2022 transfer fees come out of the stake, so the bet is sized on what the vault receives
+    let credited = vault::amount_after_fee(&ctx.accounts.mint.to_account_info(), amount)?;
+    let payout = kind.payout(selection, credited)?;
+    let vault_balance = vault::balance(casino.vault_ref(), &ctx.accounts.vault)?;
+    casino.validate_bet_risk(credited, kind.multiplier(), vault_balance)?;
+    casino.validate_potential_payout(payout, vault_balance)?;
+
+    let bet_index = ctx.accounts.round.bet_count;
+    let additional_liability = ctx.accounts.round.add_bet(&ctx.accounts.player.key(), kind, selection, credited, payout)?;
+    require!(
+        additional_liability <= casino.free_vault_balance(vault_balance),
+        CasinoError::InsufficientVaultFunds
+    );
+
+    vault::deposit(
+        ctx.accounts.casino.vault_ref(),
+        &ctx.accounts.vault,
+        &ctx.accounts.mint,
+        &ctx.accounts.player_token_account,
+        &ctx.accounts.player,
+        &ctx.accounts.token_program,
+        &ctx.accounts.system_program,
+        amount,
+    )?;
+
+    ctx.accounts.casino.reserve_liability(additional_liability)?;
+    ctx.accounts.casino.record_usd_volume(bet_usd);
+    let round = &mut ctx.accounts.round;
+    round.reserved_liability = round.reserved_liability
+        .checked_add(additional_liability)
+        .ok_or(CasinoError::ArithmeticOverflow)?;
+
+    let bet = &mut ctx.accounts.bet;
+    bet.round = round.key();
+    bet.player = ctx.accounts.player.key();
+    bet.bet_index = bet_index;
+    bet.kind = kind;
+    bet.selection = selection;
+    bet.amount = credited;
+    bet.payout = 0;
+    bet.claimed = false;
+    bet.created_at = now;
+    bet.bump = ctx.bumps.bet;
+
+    Ok(())
+}
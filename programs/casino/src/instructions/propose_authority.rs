@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::CasinoError;
+use crate::events::AuthorityTransferProposed;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct ProposeAuthority<'info> {
+    #[account(
+        mut,
+        has_one = authority @ CasinoError::Unauthorized,
+    )]
+    pub casino: Account<'info, Casino>,
+
+    /// Required when the transfer is for a role
+    #[account(
+        mut,
+        seeds = [ROLES_SEED, casino.key().as_ref()],
+        bump = roles.bump,
+    )]
+    pub roles: Option<Account<'info, CasinoRoles>>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<ProposeAuthority>, role: Option<Role>, proposed: Pubkey) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let casino = &mut ctx.accounts.casino;
+    let current = match role {
+        Some(role) => ctx.accounts.roles
+            .as_deref_mut()
+            .ok_or(CasinoError::AccountNotInitialized)?
+            .propose(role, proposed, now)?,
+        None => casino.propose_authority(proposed, now)?,
+    };
+
+    emit!(AuthorityTransferProposed {
+        casino: casino.key(),
+        role,
+        current,
+        proposed,
+        timestamp: now,
+    });
+
+    Ok(())
+}
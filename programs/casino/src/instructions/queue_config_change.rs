@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::CasinoError;
+use crate::events::ConfigChangeQueued;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct QueueConfigChange<'info> {
+    #[account(mut)]
+    pub casino: Account<'info, Casino>,
+
+    #[account(
+        seeds = [ROLES_SEED, casino.key().as_ref()],
+        bump = roles.bump,
+        constraint = roles.has_role(Role::Operator, &authority.key()) @ CasinoError::InsufficientPermissions,
+    )]
+    pub roles: Account<'info, CasinoRoles>,
+
+    /// Mint whose overrides change, or None for the casino defaults
+    #[account(
+        mut,
+        has_one = casino,
+    )]
+    pub casino_mint: Option<Account<'info, CasinoMint>>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<QueueConfigChange>,
+    house_edge: Option<Option<u16>>,
+    treasury_fee: Option<Option<u16>>,
+    cancellation_fee: Option<u16>,
+    max_bet: Option<u64>,
+    effective_at: i64,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let change = PendingConfigChange {
+        house_edge,
+        treasury_fee,
+        cancellation_fee,
+        max_bet,
+        queued_at: now,
+        effective_at,
+        by_governance: false,
+    };
+
+    // Once holders govern, only a passed proposal may raise the edge, fees or max bet
+    let governed = ctx.accounts.casino.governance.is_some();
+    let mint = match &mut ctx.accounts.casino_mint {
+        Some(casino_mint) => {
+            require!(
+                !governed || !casino_mint.is_increase(&ctx.accounts.casino, &change),
+                CasinoError::OperationNotAllowed
+            );
+            casino_mint.queue_config_change(change)?;
+            Some(casino_mint.mint)
+        },
+        None => {
+            require!(
+                !governed || !ctx.accounts.casino.is_increase(&change),
+                CasinoError::OperationNotAllowed
+            );
+            ctx.accounts.casino.queue_config_change(change)?;
+            None
+        },
+    };
+
+    emit!(ConfigChangeQueued {
+        casino: ctx.accounts.casino.key(),
+        mint,
+        change,
+        timestamp: now,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenInterface};
+
+use crate::errors::CasinoError;
+use crate::state::*;
+use crate::vault;
+
+#[derive(Accounts)]
+pub struct RefundRouletteBet<'info> {
+    #[account(mut)]
+    pub casino: Account<'info, Casino>,
+
+    #[account(
+        mut,
+        has_one = casino,
+        seeds = [ROULETTE_ROUND_SEED, casino.key().as_ref(), &round.round_id.to_le_bytes()],
+        bump = round.bump
+    )]
+    pub round: Account<'info, RouletteRound>,
+
+    #[account(
+        mut,
+        has_one = round,
+        seeds = [ROULETTE_BET_SEED, round.key().as_ref(), &bet.bet_index.to_le_bytes()],
+        bump = bet.bump
+    )]
+    pub bet: Account<'info, RouletteBet>,
+
+    /// CHECK: token account or native SOL vault PDA, validated against `casino.vault`
+    #[account(
+        mut,
+        address = casino.vault @ CasinoError::InvalidTokenAccount,
+    )]
+    pub vault: UncheckedAccount<'info>,
+
+    /// Mint of the vault, validated in `vault`
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: player token account, or the player's wallet for native SOL casinos; validated in `vault`
+    #[account(mut)]
+    pub player_token_account: UncheckedAccount<'info>,
+
+    /// Anyone may refund a bet in an unspun round
+    pub caller: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<RefundRouletteBet>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    require!(ctx.accounts.round.is_refundable(now), CasinoError::OperationNotAllowed);
+    require!(!ctx.accounts.bet.claimed, CasinoError::GameAlreadyClaimed);
+
+    // The first refund swaps the round's pocket liability for what the refunds pay out
+    let round = &mut ctx.accounts.round;
+    if matches!(round.status, RouletteRoundStatus::Open) {
+        let casino = &mut ctx.accounts.casino;
+        casino.release_liability(round.reserved_liability);
+        casino.reserve_liability(round.refund_total())?;
+        round.reserved_liability = round.refund_total();
+        round.status = RouletteRoundStatus::Refunded;
+    }
+
+    let refund = round.refund_for(ctx.accounts.bet.amount)?;
+    vault::pay_out(
+        &ctx.accounts.casino,
+        ctx.accounts.casino.vault_ref(),
+        &ctx.accounts.vault,
+        &ctx.accounts.mint,
+        &ctx.accounts.player_token_account,
+        &ctx.accounts.bet.player,
+        &ctx.accounts.token_program,
+        &ctx.accounts.system_program,
+        refund,
+    )?;
+
+    ctx.accounts.casino.record_expired_game(refund);
+    let round = &mut ctx.accounts.round;
+    round.reserved_liability = round.reserved_liability.saturating_sub(refund);
+
+    let bet = &mut ctx.accounts.bet;
+    bet.payout = refund;
+    bet.claimed = true;
+
+    Ok(())
+}
@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+use crate::errors::CasinoError;
+use crate::events::LiquidityWithdrawalRequested;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct RequestLiquidityWithdrawal<'info> {
+    #[account(
+        mut,
+        has_one = share_mint,
+        seeds = [LIQUIDITY_POOL_SEED, liquidity_pool.casino.as_ref()],
+        bump = liquidity_pool.bump
+    )]
+    pub liquidity_pool: Account<'info, LiquidityPool>,
+
+    pub share_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [LP_ESCROW_SEED, liquidity_pool.key().as_ref()],
+        bump,
+    )]
+    pub share_escrow: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init,
+        payer = provider,
+        space = WithdrawalRequest::LEN,
+        seeds = [LP_WITHDRAWAL_SEED, liquidity_pool.key().as_ref(), provider.key().as_ref()],
+        bump
+    )]
+    pub withdrawal_request: Account<'info, WithdrawalRequest>,
+
+    #[account(
+        mut,
+        token::mint = share_mint,
+        token::authority = provider,
+    )]
+    pub provider_share_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub provider: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<RequestLiquidityWithdrawal>, shares: u64) -> Result<()> {
+    require!(shares > 0, CasinoError::InvalidInstructionData);
+
+    token_interface::transfer_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.provider_share_account.to_account_info(),
+                mint: ctx.accounts.share_mint.to_account_info(),
+                to: ctx.accounts.share_escrow.to_account_info(),
+                authority: ctx.accounts.provider.to_account_info(),
+            },
+        ),
+        shares,
+        ctx.accounts.share_mint.decimals,
+    )?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let liquidity_pool = &mut ctx.accounts.liquidity_pool;
+    liquidity_pool.pending_shares = liquidity_pool.pending_shares
+        .checked_add(shares)
+        .ok_or(CasinoError::ArithmeticOverflow)?;
+
+    let request = &mut ctx.accounts.withdrawal_request;
+    request.pool = liquidity_pool.key();
+    request.provider = ctx.accounts.provider.key();
+    request.shares = shares;
+    request.requested_at = now;
+    request.unlocks_at = now + liquidity_pool.cooldown_period;
+    request.bump = ctx.bumps.withdrawal_request;
+
+    emit!(LiquidityWithdrawalRequested {
+        pool: request.pool,
+        provider: request.provider,
+        shares,
+        unlocks_at: request.unlocks_at,
+        timestamp: now,
+    });
+
+    Ok(())
+}
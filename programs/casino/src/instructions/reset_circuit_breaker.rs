@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::CasinoError;
+use crate::events::CircuitBreakerReset;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct ResetCircuitBreaker<'info> {
+    #[account(mut)]
+    pub casino: Account<'info, Casino>,
+
+    /// Mint whose breaker is reset, or None for the primary mint's
+    #[account(
+        mut,
+        has_one = casino,
+    )]
+    pub casino_mint: Option<Account<'info, CasinoMint>>,
+
+    #[account(
+        seeds = [ROLES_SEED, casino.key().as_ref()],
+        bump = roles.bump,
+        constraint = roles.has_role(Role::Operator, &authority.key()) @ CasinoError::InsufficientPermissions,
+    )]
+    pub roles: Account<'info, CasinoRoles>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<ResetCircuitBreaker>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let mint = match ctx.accounts.casino_mint.as_mut() {
+        Some(casino_mint) => {
+            require!(casino_mint.circuit_breaker.is_tripped(), CasinoError::InvalidStateTransition);
+            casino_mint.circuit_breaker.reset(now);
+            casino_mint.updated_at = now;
+            Some(casino_mint.mint)
+        },
+        None => {
+            let casino = &mut ctx.accounts.casino;
+            require!(casino.circuit_breaker.is_tripped(), CasinoError::InvalidStateTransition);
+            casino.circuit_breaker.reset(now);
+            casino.updated_at = now;
+            None
+        },
+    };
+
+    emit!(CircuitBreakerReset {
+        casino: ctx.accounts.casino.key(),
+        mint,
+        authority: ctx.accounts.authority.key(),
+        timestamp: now,
+    });
+
+    Ok(())
+}
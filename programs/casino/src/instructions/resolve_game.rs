@@ -0,0 +1,135 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::CasinoError;
+use crate::events::{CircuitBreakerTripped, GameResolved};
+use crate::state::*;
+use crate::vault;
+
+#[derive(Accounts)]
+pub struct ResolveGame<'info> {
+    #[account(mut)]
+    pub casino: Account<'info, Casino>,
+
+    /// Required when the game was played in a non-primary mint
+    #[account(
+        mut,
+        has_one = casino,
+        seeds = [CASINO_MINT_SEED, casino.key().as_ref(), casino_mint.mint.as_ref()],
+        bump = casino_mint.bump
+    )]
+    pub casino_mint: Option<Account<'info, CasinoMint>>,
+
+    #[account(
+        seeds = [ROLES_SEED, casino.key().as_ref()],
+        bump = roles.bump,
+        constraint = roles.has_role(Role::Resolver, &authority.key()) @ CasinoError::InsufficientPermissions,
+    )]
+    pub roles: Account<'info, CasinoRoles>,
+
+    #[account(
+        mut,
+        has_one = casino,
+    )]
+    pub game: Account<'info, Game>,
+
+    /// CHECK: token account or native SOL vault PDA, validated in `vault`
+    pub vault: UncheckedAccount<'info>,
+
+    /// CHECK: the casino's jackpot PDA, loaded in `Jackpot::load` when the game contributed
+    #[account(
+        mut,
+        seeds = [JACKPOT_SEED, casino.key().as_ref()],
+        bump
+    )]
+    pub jackpot: UncheckedAccount<'info>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<ResolveGame>, server_seed: String, nonce: u64) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let game = &ctx.accounts.game;
+    require!(game.can_be_resolved(), CasinoError::CannotResolveGame);
+    require!(nonce == game.provable_fair.nonce, CasinoError::InvalidNonce);
+    require!(
+        game.verify_provable_fairness(&server_seed)?,
+        CasinoError::ProvableFairnessVerificationFailed
+    );
+
+    let vault_ref = game.vault_ref(&ctx.accounts.casino, ctx.accounts.casino_mint.as_deref())?;
+
+    // Settle with the edge and fee the player bet under, not the current ones
+    let outcome = game.generate_game_outcome(&server_seed)?;
+    let mut result = game.calculate_payout(&outcome, game.house_edge)?;
+    let total_wager = game.total_wager()?;
+    result.treasury_fee_taken = (total_wager as u128 * game.treasury_fee as u128 / BASIS_POINTS as u128) as u64;
+    let potential_payout = game.potential_payout()?;
+    let vault_balance = vault::balance(vault_ref, &ctx.accounts.vault)?;
+
+    // Only the actual payout stays reserved until it is claimed
+    match ctx.accounts.casino_mint.as_mut() {
+        Some(casino_mint) => {
+            let was_tripped = casino_mint.circuit_breaker.is_tripped();
+
+            casino_mint.release_liability(potential_payout);
+            casino_mint.reserve_liability(result.payout)?;
+            casino_mint.update_stats(total_wager, result.payout, result.house_edge_taken, result.treasury_fee_taken);
+            casino_mint.accrue_treasury_fee(result.treasury_fee_taken);
+            casino_mint.circuit_breaker.record_payout(result.payout, vault_balance, now);
+
+            if !was_tripped && casino_mint.circuit_breaker.is_tripped() {
+                emit!(CircuitBreakerTripped {
+                    casino: casino_mint.casino,
+                    mint: Some(casino_mint.mint),
+                    window_pnl: casino_mint.circuit_breaker.window_pnl,
+                    timestamp: now,
+                });
+            }
+        },
+        None => {
+            let casino = &mut ctx.accounts.casino;
+            let was_tripped = casino.circuit_breaker.is_tripped();
+
+            casino.release_liability(potential_payout);
+            casino.reserve_liability(result.payout)?;
+            casino.update_stats(total_wager, result.payout, result.house_edge_taken, result.treasury_fee_taken);
+            casino.accrue_treasury_fee(result.treasury_fee_taken);
+            casino.circuit_breaker.record_payout(result.payout, vault_balance, now);
+
+            if !was_tripped && casino.circuit_breaker.is_tripped() {
+                emit!(CircuitBreakerTripped {
+                    casino: casino.key(),
+                    mint: None,
+                    window_pnl: casino.circuit_breaker.window_pnl,
+                    timestamp: now,
+                });
+            }
+        },
+    }
+
+    let game = &mut ctx.accounts.game;
+    game.provable_fair.server_seed = Some(server_seed.clone());
+    game.resolve_game(result.clone())?;
+
+    // Lock the jackpot share now, so it cannot shrink or grow before the claim
+    if game.jackpot_contribution > 0 {
+        let mut jackpot = Jackpot::load(&ctx.accounts.jackpot)?.ok_or(CasinoError::AccountNotInitialized)?;
+        if jackpot.is_triggered(game, &server_seed) {
+            game.jackpot_payout = jackpot.lock_win(game.player, now);
+            jackpot.store(&ctx.accounts.jackpot)?;
+        }
+    }
+
+    emit!(GameResolved {
+        game: game.key(),
+        casino: game.casino,
+        player: game.player,
+        outcome: result.outcome,
+        multiplier: result.multiplier,
+        payout: result.payout,
+        server_seed,
+        timestamp: now,
+    });
+
+    Ok(())
+}
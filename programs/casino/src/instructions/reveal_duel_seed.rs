@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::CasinoError;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct RevealDuelSeed<'info> {
+    #[account(
+        mut,
+        seeds = [DUEL_SEED, duel.casino.as_ref(), duel.creator.as_ref(), &duel.duel_id.to_le_bytes()],
+        bump = duel.bump
+    )]
+    pub duel: Account<'info, Duel>,
+
+    pub player: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<RevealDuelSeed>, client_seed: String) -> Result<()> {
+    require!(
+        !client_seed.is_empty() && client_seed.len() <= MAX_CLIENT_SEED_LEN,
+        CasinoError::InvalidClientSeed
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts.duel.reveal_seed(&ctx.accounts.player.key(), client_seed, now)
+}
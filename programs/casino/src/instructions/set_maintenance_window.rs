@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::CasinoError;
+use crate::events::MaintenanceScheduled;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct SetMaintenanceWindow<'info> {
+    #[account(mut)]
+    pub casino: Account<'info, Casino>,
+
+    #[account(
+        seeds = [ROLES_SEED, casino.key().as_ref()],
+        bump = roles.bump,
+        constraint = roles.has_role(Role::Operator, &authority.key()) @ CasinoError::InsufficientPermissions,
+    )]
+    pub roles: Account<'info, CasinoRoles>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetMaintenanceWindow>, window: Option<MaintenanceWindow>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let casino = &mut ctx.accounts.casino;
+    if let Some(window) = &window {
+        window.validate(now)?;
+        // A running window can be ended early, but not moved or stretched
+        require!(!casino.is_in_maintenance(now), CasinoError::MaintenanceModeActive);
+    }
+
+    casino.maintenance_window = window;
+    casino.updated_at = now;
+
+    emit!(MaintenanceScheduled {
+        casino: casino.key(),
+        authority: ctx.accounts.authority.key(),
+        window,
+        timestamp: now,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::CasinoError;
+use crate::oracle;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct SetUsdLimits<'info> {
+    #[account(mut)]
+    pub casino: Account<'info, Casino>,
+
+    #[account(
+        seeds = [ROLES_SEED, casino.key().as_ref()],
+        bump = roles.bump,
+        constraint = roles.has_role(Role::Operator, &authority.key()) @ CasinoError::InsufficientPermissions,
+    )]
+    pub roles: Account<'info, CasinoRoles>,
+
+    /// CHECK: Switchboard aggregator or `PriceCache` for the casino token, validated in
+    /// `oracle`; omit to fall back to raw token limits
+    pub price_feed: Option<UncheckedAccount<'info>>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<SetUsdLimits>,
+    source: PriceSourceKind,
+    min_bet_usd: u64,
+    max_bet_usd: u64,
+    max_staleness: u32,
+    max_confidence_bps: u16,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+
+    let usd_limits = match &ctx.accounts.price_feed {
+        Some(price_feed) => {
+            let limits = UsdLimits {
+                source,
+                price_feed: price_feed.key(),
+                min_bet_usd,
+                max_bet_usd,
+                max_staleness,
+                max_confidence_bps,
+            };
+            limits.validate_config()?;
+
+            if source == PriceSourceKind::AmmTwap {
+                let price_cache = oracle::load_price_cache(price_feed)?;
+                require_keys_eq!(price_cache.casino, ctx.accounts.casino.key(), CasinoError::OraclePriceFeedInvalid);
+                require_keys_eq!(price_cache.mint, ctx.accounts.casino.token_mint, CasinoError::OraclePriceFeedInvalid);
+            }
+
+            // Refuse to switch over to a feed that would reject every bet
+            oracle::token_price(source, price_feed, now, max_staleness, max_confidence_bps)?;
+            Some(limits)
+        },
+        None => None,
+    };
+
+    let casino = &mut ctx.accounts.casino;
+    casino.usd_limits = usd_limits;
+    casino.updated_at = now;
+
+    Ok(())
+}
@@ -0,0 +1,192 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, CloseAccount, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+use crate::errors::CasinoError;
+use crate::events::DuelSettled;
+use crate::state::*;
+use crate::vault;
+
+#[derive(Accounts)]
+pub struct SettleDuel<'info> {
+    #[account(mut)]
+    pub casino: Account<'info, Casino>,
+
+    #[account(
+        mut,
+        has_one = casino,
+        has_one = creator,
+        seeds = [DUEL_SEED, casino.key().as_ref(), duel.creator.as_ref(), &duel.duel_id.to_le_bytes()],
+        bump = duel.bump
+    )]
+    pub duel: Account<'info, Duel>,
+
+    #[account(
+        mut,
+        seeds = [DUEL_ESCROW_SEED, duel.key().as_ref()],
+        bump,
+    )]
+    pub escrow: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        address = casino.token_mint @ CasinoError::InvalidTokenMint,
+    )]
+    pub token_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        constraint = creator_token_account.owner == duel.creator @ CasinoError::InvalidTokenAccount,
+        constraint = creator_token_account.mint == casino.token_mint @ CasinoError::InvalidTokenMint,
+    )]
+    pub creator_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = Some(opponent_token_account.owner) == duel.opponent @ CasinoError::InvalidTokenAccount,
+        constraint = opponent_token_account.mint == casino.token_mint @ CasinoError::InvalidTokenMint,
+    )]
+    pub opponent_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: treasury token account, or wallet for native SOL casinos; validated against
+    /// `casino.treasury`, which is checked when the casino is initialized
+    #[account(
+        mut,
+        address = casino.treasury @ CasinoError::InvalidTokenAccount,
+    )]
+    pub treasury: UncheckedAccount<'info>,
+
+    /// CHECK: receives the escrow rent, validated against `duel.creator`
+    #[account(mut)]
+    pub creator: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handler(ctx: Context<SettleDuel>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let duel = &ctx.accounts.duel;
+    let winner = duel.determine_winner(now)?;
+
+    let casino_key = ctx.accounts.casino.key();
+    let duel_id_bytes = duel.duel_id.to_le_bytes();
+    let seeds = &[
+        DUEL_SEED,
+        casino_key.as_ref(),
+        duel.creator.as_ref(),
+        &duel_id_bytes,
+        &[duel.bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    // Stakes arrive net of any transfer fee, so settle what the escrow actually holds
+    let pot = ctx.accounts.escrow.amount;
+    // A native casino's treasury is a wallet, so its fee stays wrapped in the escrow
+    // until the escrow is closed
+    let native = ctx.accounts.casino.is_native();
+    let (payout, treasury_fee) = match winner {
+        Some(winner) => {
+            let treasury_fee = duel.calculate_treasury_fee(pot, ctx.accounts.casino.treasury_fee);
+            let payout = pot.checked_sub(treasury_fee).ok_or(CasinoError::ArithmeticUnderflow)?;
+            // Gross the winner's transfer up so the payout arrives in full, out of the treasury's cut
+            let winner_transfer = vault::amount_before_fee(&ctx.accounts.token_mint.to_account_info(), payout)?
+                .min(pot);
+            let treasury_fee = pot - winner_transfer;
+
+            let winner_account = if winner == duel.creator {
+                ctx.accounts.creator_token_account.to_account_info()
+            } else {
+                ctx.accounts.opponent_token_account.to_account_info()
+            };
+            transfer_from_escrow(&ctx, winner_account, winner_transfer, signer)?;
+            if !native {
+                transfer_from_escrow(&ctx, ctx.accounts.treasury.to_account_info(), treasury_fee, signer)?;
+            }
+
+            (payout, treasury_fee)
+        },
+        None => {
+            // Neither player revealed in time, refund both stakes
+            let opponent_refund = pot / 2;
+            let creator_refund = pot - opponent_refund;
+            transfer_from_escrow(&ctx, ctx.accounts.creator_token_account.to_account_info(), creator_refund, signer)?;
+            transfer_from_escrow(&ctx, ctx.accounts.opponent_token_account.to_account_info(), opponent_refund, signer)?;
+
+            (0, 0)
+        },
+    };
+
+    vault::harvest_withheld_fees(
+        &ctx.accounts.escrow.to_account_info(),
+        &ctx.accounts.token_mint.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+    )?;
+    // Closing a wrapped SOL escrow unwraps the fee left in it along with the rent, so
+    // it goes through the duel account, which forwards the fee to the treasury
+    let duel_info = ctx.accounts.duel.to_account_info();
+    let lamports_before = duel_info.lamports();
+    token_interface::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.escrow.to_account_info(),
+            destination: if native { duel_info.clone() } else { ctx.accounts.creator.to_account_info() },
+            authority: duel_info.clone(),
+        },
+        signer,
+    ))?;
+    if native {
+        let unwrapped = duel_info.lamports().saturating_sub(lamports_before);
+        let to_treasury = treasury_fee.min(unwrapped);
+        **duel_info.try_borrow_mut_lamports()? -= unwrapped;
+        **ctx.accounts.treasury.try_borrow_mut_lamports()? += to_treasury;
+        **ctx.accounts.creator.try_borrow_mut_lamports()? += unwrapped - to_treasury;
+    }
+
+    if winner.is_some() {
+        ctx.accounts.casino.record_duel(pot, treasury_fee);
+        ctx.accounts.casino.record_usd_volume(duel.stake_usd.map(|stake_usd| stake_usd.saturating_mul(2)));
+    }
+
+    let duel = &mut ctx.accounts.duel;
+    duel.winner = winner;
+    duel.payout = payout;
+    duel.treasury_fee_taken = treasury_fee;
+    duel.status = DuelStatus::Settled;
+    duel.settled_at = Some(now);
+
+    emit!(DuelSettled {
+        duel: duel.key(),
+        casino: duel.casino,
+        winner,
+        payout,
+        treasury_fee,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+fn transfer_from_escrow<'info>(
+    ctx: &Context<SettleDuel<'info>>,
+    to: AccountInfo<'info>,
+    amount: u64,
+    signer: &[&[&[u8]]],
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.escrow.to_account_info(),
+                mint: ctx.accounts.token_mint.to_account_info(),
+                to,
+                authority: ctx.accounts.duel.to_account_info(),
+            },
+            signer,
+        ),
+        amount,
+        ctx.accounts.token_mint.decimals,
+    )
+}
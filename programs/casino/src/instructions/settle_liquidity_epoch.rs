@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::errors::CasinoError;
+use crate::state::*;
+use crate::vault;
+
+#[derive(Accounts)]
+pub struct SettleLiquidityEpoch<'info> {
+    pub casino: Account<'info, Casino>,
+
+    #[account(
+        mut,
+        has_one = casino,
+        has_one = share_mint,
+        seeds = [LIQUIDITY_POOL_SEED, casino.key().as_ref()],
+        bump = liquidity_pool.bump
+    )]
+    pub liquidity_pool: Account<'info, LiquidityPool>,
+
+    pub share_mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: token account or native SOL vault PDA, validated against `casino.vault`
+    #[account(
+        address = casino.vault @ CasinoError::InvalidTokenAccount,
+    )]
+    pub vault: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<SettleLiquidityEpoch>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    require!(ctx.accounts.liquidity_pool.can_settle_epoch(now), CasinoError::OperationNotAllowed);
+
+    let casino = &ctx.accounts.casino;
+    let vault_balance = vault::balance(casino.vault_ref(), &ctx.accounts.vault)?;
+    let pool_value = casino.free_vault_balance(vault_balance);
+
+    ctx.accounts.liquidity_pool.settle_epoch(pool_value, ctx.accounts.share_mint.supply, now);
+
+    Ok(())
+}
@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::slot_hashes;
+
+use crate::errors::CasinoError;
+use crate::events::{CircuitBreakerTripped, RouletteRoundSpun};
+use crate::state::*;
+use crate::vault;
+
+#[derive(Accounts)]
+pub struct SpinRouletteRound<'info> {
+    #[account(mut)]
+    pub casino: Account<'info, Casino>,
+
+    #[account(
+        seeds = [ROLES_SEED, casino.key().as_ref()],
+        bump = roles.bump,
+        constraint = roles.has_role(Role::Resolver, &authority.key()) @ CasinoError::InsufficientPermissions,
+    )]
+    pub roles: Account<'info, CasinoRoles>,
+
+    #[account(
+        mut,
+        has_one = casino,
+        seeds = [ROULETTE_ROUND_SEED, casino.key().as_ref(), &round.round_id.to_le_bytes()],
+        bump = round.bump
+    )]
+    pub round: Account<'info, RouletteRound>,
+
+    /// CHECK: token account or native SOL vault PDA, validated against `casino.vault`
+    #[account(
+        address = casino.vault @ CasinoError::InvalidTokenAccount,
+    )]
+    pub vault: UncheckedAccount<'info>,
+
+    /// CHECK: SlotHashes sysvar, read in `find_slot_hash`
+    #[account(
+        address = slot_hashes::ID @ CasinoError::InvalidProgramId,
+    )]
+    pub slot_hashes: UncheckedAccount<'info>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SpinRouletteRound>, server_seed: String) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let round = &ctx.accounts.round;
+    require!(matches!(round.status, RouletteRoundStatus::Open), CasinoError::GameAlreadyResolved);
+    require!(now >= round.closes_at, CasinoError::OperationNotAllowed);
+    require!(!round.is_refundable(now), CasinoError::GameExpired);
+    require!(round.verify_server_seed(&server_seed), CasinoError::ProvableFairnessVerificationFailed);
+
+    let slot_hash = find_slot_hash(&ctx.accounts.slot_hashes.try_borrow_data()?, round.entropy_slot)
+        .ok_or(CasinoError::RandomnessNotAvailable)?;
+    let result = round.spin(&server_seed, &slot_hash);
+    let bet_count = round.bet_count;
+    let winning_payout = round.payouts_by_pocket[result as usize];
+    let total_staked = round.total_staked;
+
+    let vault_balance = vault::balance(ctx.accounts.casino.vault_ref(), &ctx.accounts.vault)?;
+    let casino = &mut ctx.accounts.casino;
+    let was_tripped = casino.circuit_breaker.is_tripped();
+
+    // Only the winning pocket's payouts stay reserved until they are claimed
+    casino.release_liability(round.reserved_liability);
+    casino.reserve_liability(winning_payout)?;
+    casino.update_round_stats(bet_count, total_staked, winning_payout);
+    casino.circuit_breaker.record_payout(winning_payout, vault_balance, now);
+
+    if !was_tripped && casino.circuit_breaker.is_tripped() {
+        emit!(CircuitBreakerTripped {
+            casino: casino.key(),
+            mint: None,
+            window_pnl: casino.circuit_breaker.window_pnl,
+            timestamp: now,
+        });
+    }
+
+    let round = &mut ctx.accounts.round;
+    round.server_seed = Some(server_seed.clone());
+    round.result = Some(result);
+    round.slot_hash = Some(slot_hash);
+    round.reserved_liability = winning_payout;
+    round.status = RouletteRoundStatus::Spun;
+    round.spun_at = Some(now);
+
+    emit!(RouletteRoundSpun {
+        round: round.key(),
+        casino: round.casino,
+        result,
+        server_seed,
+        slot_hash,
+        total_staked,
+        winning_payout,
+        timestamp: now,
+    });
+
+    Ok(())
+}
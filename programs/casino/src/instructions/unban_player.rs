@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::CasinoError;
+use crate::events::PlayerUnbanned;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct UnbanPlayer<'info> {
+    pub casino: Account<'info, Casino>,
+
+    #[account(
+        seeds = [ROLES_SEED, casino.key().as_ref()],
+        bump = roles.bump,
+        constraint = roles.has_role(Role::Compliance, &authority.key()) @ CasinoError::InsufficientPermissions,
+    )]
+    pub roles: Account<'info, CasinoRoles>,
+
+    #[account(
+        mut,
+        has_one = casino,
+        seeds = [PLAYER_BAN_SEED, casino.key().as_ref(), player_ban.player.as_ref()],
+        bump = player_ban.bump,
+        close = authority,
+    )]
+    pub player_ban: Account<'info, PlayerBan>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<UnbanPlayer>) -> Result<()> {
+    emit!(PlayerUnbanned {
+        casino: ctx.accounts.casino.key(),
+        player: ctx.accounts.player_ban.player,
+        authority: ctx.accounts.authority.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
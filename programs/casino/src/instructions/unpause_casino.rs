@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::CasinoError;
+use crate::events::CasinoUnpaused;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct UnpauseCasino<'info> {
+    #[account(mut)]
+    pub casino: Account<'info, Casino>,
+
+    #[account(
+        seeds = [ROLES_SEED, casino.key().as_ref()],
+        bump = roles.bump,
+        constraint = roles.has_role(Role::Operator, &authority.key()) @ CasinoError::InsufficientPermissions,
+    )]
+    pub roles: Account<'info, CasinoRoles>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<UnpauseCasino>, reason: PauseReason, resolve_only: bool) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let casino = &mut ctx.accounts.casino;
+    casino.resume(ctx.accounts.authority.key(), reason, resolve_only, now)?;
+
+    emit!(CasinoUnpaused {
+        casino: casino.key(),
+        authority: ctx.accounts.authority.key(),
+        reason,
+        resolve_only,
+        timestamp: now,
+    });
+
+    Ok(())
+}
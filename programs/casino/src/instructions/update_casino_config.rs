@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::CasinoError;
+use crate::events::CasinoConfigUpdated;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct UpdateCasinoConfig<'info> {
+    #[account(mut)]
+    pub casino: Account<'info, Casino>,
+
+    #[account(
+        seeds = [ROLES_SEED, casino.key().as_ref()],
+        bump = roles.bump,
+        constraint = roles.has_role(Role::Operator, &authority.key()) @ CasinoError::InsufficientPermissions,
+    )]
+    pub roles: Account<'info, CasinoRoles>,
+
+    pub authority: Signer<'info>,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn handler(
+    ctx: Context<UpdateCasinoConfig>,
+    house_edge: Option<u16>,
+    min_bet: Option<u64>,
+    max_bet: Option<u64>,
+    is_active: Option<bool>,
+    max_payout: Option<u64>,
+    cancellation_fee: Option<u16>,
+    unpause_delay: Option<i64>,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let casino = &mut ctx.accounts.casino;
+
+    // Cuts apply immediately; increases, max bet included, have to wait out the timelock
+    if let Some(house_edge) = house_edge {
+        require!(house_edge <= casino.house_edge, CasinoError::OperationNotAllowed);
+        casino.house_edge = house_edge;
+        casino.validate_house_edge()?;
+    }
+    if let Some(cancellation_fee) = cancellation_fee {
+        require!(cancellation_fee <= casino.cancellation_fee, CasinoError::OperationNotAllowed);
+        casino.cancellation_fee = cancellation_fee;
+        casino.validate_cancellation_fee()?;
+    }
+    if let Some(min_bet) = min_bet {
+        casino.min_bet = min_bet;
+    }
+    if let Some(max_bet) = max_bet {
+        require!(max_bet <= casino.max_bet, CasinoError::OperationNotAllowed);
+        casino.max_bet = max_bet;
+    }
+    require!(
+        casino.min_bet > 0 && casino.max_bet >= casino.min_bet,
+        CasinoError::InvalidConfiguration
+    );
+    if let Some(is_active) = is_active {
+        casino.is_active = is_active;
+    }
+    if let Some(max_payout) = max_payout {
+        casino.max_payout = max_payout;
+    }
+    if let Some(unpause_delay) = unpause_delay {
+        casino.unpause_delay = unpause_delay;
+        casino.validate_unpause_delay()?;
+    }
+    casino.updated_at = now;
+
+    emit!(CasinoConfigUpdated {
+        casino: casino.key(),
+        authority: ctx.accounts.authority.key(),
+        house_edge: casino.house_edge,
+        min_bet: casino.min_bet,
+        max_bet: casino.max_bet,
+        is_active: casino.is_active,
+        timestamp: now,
+    });
+
+    Ok(())
+}
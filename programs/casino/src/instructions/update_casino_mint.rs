@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::CasinoError;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct UpdateCasinoMint<'info> {
+    pub casino: Account<'info, Casino>,
+
+    #[account(
+        seeds = [ROLES_SEED, casino.key().as_ref()],
+        bump = roles.bump,
+        constraint = roles.has_role(Role::Operator, &authority.key()) @ CasinoError::InsufficientPermissions,
+    )]
+    pub roles: Account<'info, CasinoRoles>,
+
+    #[account(
+        mut,
+        has_one = casino,
+        seeds = [CASINO_MINT_SEED, casino.key().as_ref(), casino_mint.mint.as_ref()],
+        bump = casino_mint.bump
+    )]
+    pub casino_mint: Account<'info, CasinoMint>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<UpdateCasinoMint>,
+    min_bet: Option<u64>,
+    max_bet: Option<u64>,
+    max_payout: Option<u64>,
+    house_edge: Option<Option<u16>>,
+    treasury_fee: Option<Option<u16>>,
+    is_active: Option<bool>,
+) -> Result<()> {
+    let casino = &ctx.accounts.casino;
+    let casino_mint = &mut ctx.accounts.casino_mint;
+
+    if let Some(min_bet) = min_bet {
+        casino_mint.min_bet = min_bet;
+    }
+    if let Some(max_payout) = max_payout {
+        casino_mint.max_payout = max_payout;
+    }
+    // Only cuts apply immediately, increases go through queue_config_change
+    if let Some(max_bet) = max_bet {
+        require!(max_bet <= casino_mint.max_bet, CasinoError::OperationNotAllowed);
+        casino_mint.max_bet = max_bet;
+    }
+    if let Some(house_edge) = house_edge {
+        require!(
+            house_edge.unwrap_or(casino.house_edge) <= casino_mint.effective_house_edge(casino),
+            CasinoError::OperationNotAllowed
+        );
+        casino_mint.house_edge = house_edge;
+    }
+    if let Some(treasury_fee) = treasury_fee {
+        require!(
+            treasury_fee.unwrap_or(casino.treasury_fee) <= casino_mint.effective_treasury_fee(casino),
+            CasinoError::OperationNotAllowed
+        );
+        casino_mint.treasury_fee = treasury_fee;
+    }
+    if let Some(is_active) = is_active {
+        casino_mint.is_active = is_active;
+    }
+    casino_mint.updated_at = Clock::get()?.unix_timestamp;
+
+    casino_mint.validate_config()
+}
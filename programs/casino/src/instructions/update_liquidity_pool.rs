@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::CasinoError;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct UpdateLiquidityPool<'info> {
+    pub casino: Account<'info, Casino>,
+
+    #[account(
+        seeds = [ROLES_SEED, casino.key().as_ref()],
+        bump = roles.bump,
+        constraint = roles.has_role(Role::Operator, &authority.key()) @ CasinoError::InsufficientPermissions,
+    )]
+    pub roles: Account<'info, CasinoRoles>,
+
+    #[account(
+        mut,
+        has_one = casino,
+        seeds = [LIQUIDITY_POOL_SEED, casino.key().as_ref()],
+        bump = liquidity_pool.bump
+    )]
+    pub liquidity_pool: Account<'info, LiquidityPool>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<UpdateLiquidityPool>,
+    deposit_cap: Option<u64>,
+    cooldown_period: Option<i64>,
+    epoch_duration: Option<i64>,
+) -> Result<()> {
+    let liquidity_pool = &mut ctx.accounts.liquidity_pool;
+
+    if let Some(deposit_cap) = deposit_cap {
+        liquidity_pool.deposit_cap = deposit_cap;
+    }
+    if let Some(cooldown_period) = cooldown_period {
+        liquidity_pool.cooldown_period = cooldown_period;
+    }
+    if let Some(epoch_duration) = epoch_duration {
+        liquidity_pool.epoch_duration = epoch_duration;
+    }
+    liquidity_pool.updated_at = Clock::get()?.unix_timestamp;
+
+    liquidity_pool.validate_config()
+}
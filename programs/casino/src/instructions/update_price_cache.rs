@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::oracle;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct UpdatePriceCache<'info> {
+    #[account(
+        mut,
+        seeds = [PRICE_CACHE_SEED, price_cache.casino.as_ref(), price_cache.mint.as_ref()],
+        bump = price_cache.bump
+    )]
+    pub price_cache: Account<'info, PriceCache>,
+
+    /// CHECK: pool base reserve token account, or the mock pool; validated in `oracle`
+    pub base_reserve: UncheckedAccount<'info>,
+
+    /// CHECK: pool quote reserve token account; validated in `oracle`
+    pub quote_reserve: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<UpdatePriceCache>) -> Result<()> {
+    let price_cache = &mut ctx.accounts.price_cache;
+    let (base_reserve, quote_reserve) = oracle::pool_reserves(
+        price_cache,
+        &ctx.accounts.base_reserve,
+        &ctx.accounts.quote_reserve,
+    )?;
+    let spot_price = price_cache.spot_price(base_reserve, quote_reserve)?;
+    price_cache.record(spot_price, Clock::get()?.unix_timestamp)
+}
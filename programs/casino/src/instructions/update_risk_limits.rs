@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::CasinoError;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct UpdateRiskLimits<'info> {
+    #[account(mut)]
+    pub casino: Account<'info, Casino>,
+
+    /// Mint whose breaker limits change, or None for the primary mint's
+    #[account(
+        mut,
+        has_one = casino,
+    )]
+    pub casino_mint: Option<Account<'info, CasinoMint>>,
+
+    #[account(
+        seeds = [ROLES_SEED, casino.key().as_ref()],
+        bump = roles.bump,
+        constraint = roles.has_role(Role::Operator, &authority.key()) @ CasinoError::InsufficientPermissions,
+    )]
+    pub roles: Account<'info, CasinoRoles>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<UpdateRiskLimits>,
+    kelly_fraction: Option<u16>,
+    max_drawdown: Option<u64>,
+    drawdown_window: Option<i64>,
+    max_payout_share: Option<u16>,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let casino = &mut ctx.accounts.casino;
+
+    // The Kelly fraction is a share of free bankroll, so it applies to every vault
+    if let Some(kelly_fraction) = kelly_fraction {
+        casino.kelly_fraction = kelly_fraction;
+        casino.validate_kelly_fraction()?;
+    }
+    casino.updated_at = now;
+
+    // Drawdown is counted in token units, so each mint has its own breaker
+    let circuit_breaker = match ctx.accounts.casino_mint.as_mut() {
+        Some(casino_mint) => {
+            casino_mint.updated_at = now;
+            &mut casino_mint.circuit_breaker
+        },
+        None => &mut ctx.accounts.casino.circuit_breaker,
+    };
+    if let Some(max_drawdown) = max_drawdown {
+        circuit_breaker.max_drawdown = max_drawdown;
+    }
+    if let Some(drawdown_window) = drawdown_window {
+        circuit_breaker.set_window(drawdown_window, now);
+    }
+    if let Some(max_payout_share) = max_payout_share {
+        circuit_breaker.max_payout_share = max_payout_share;
+    }
+    circuit_breaker.validate_config()
+}
@@ -0,0 +1,124 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenInterface};
+
+use crate::errors::CasinoError;
+use crate::events::TreasuryWithdrawn;
+use crate::state::*;
+use crate::vault;
+
+#[derive(Accounts)]
+pub struct WithdrawTreasury<'info> {
+    #[account(mut)]
+    pub casino: Account<'info, Casino>,
+
+    #[account(
+        seeds = [ROLES_SEED, casino.key().as_ref()],
+        bump = roles.bump,
+        constraint = roles.has_role(Role::Treasurer, &authority.key()) @ CasinoError::InsufficientPermissions,
+    )]
+    pub roles: Account<'info, CasinoRoles>,
+
+    /// Mint whose accrued fees are withdrawn, or None for the primary mint
+    #[account(
+        mut,
+        has_one = casino,
+    )]
+    pub casino_mint: Option<Account<'info, CasinoMint>>,
+
+    /// CHECK: token account or native SOL vault PDA, validated in `vault`
+    #[account(mut)]
+    pub vault: UncheckedAccount<'info>,
+
+    /// Mint of the vault, validated in `vault`
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: treasury token account, or wallet for native SOL casinos; validated in `vault`
+    #[account(
+        mut,
+        address = casino.treasury @ CasinoError::InvalidTokenAccount,
+    )]
+    pub treasury: UncheckedAccount<'info>,
+
+    /// CHECK: token account for `casino_mint` owned by the treasury's owner, required
+    /// with `casino_mint`; validated in `vault`
+    #[account(mut)]
+    pub mint_treasury: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: the casino's liquidity pool PDA; once it exists, the free vault belongs to LPs
+    #[account(
+        seeds = [LIQUIDITY_POOL_SEED, casino.key().as_ref()],
+        bump
+    )]
+    pub liquidity_pool: UncheckedAccount<'info>,
+
+    pub authority: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<WithdrawTreasury>, amount: u64) -> Result<()> {
+    require!(amount > 0, CasinoError::InvalidInstructionData);
+    let casino = &ctx.accounts.casino;
+    let treasury_owner = vault::recipient_of(casino.vault_ref(), &ctx.accounts.treasury)?;
+
+    // Funds backing unsettled games never leave a vault. Other mints have no LPs,
+    // so their free balance is the house's; once LPs own the primary vault's free
+    // balance only accrued treasury fees can leave it.
+    let (vault_ref, available, destination) = match ctx.accounts.casino_mint.as_deref() {
+        Some(casino_mint) => {
+            let vault_balance = vault::balance(casino_mint.vault_ref(), &ctx.accounts.vault)?;
+            let destination = ctx.accounts.mint_treasury.as_ref().ok_or(CasinoError::InvalidTokenAccount)?;
+            (
+                casino_mint.vault_ref(),
+                vault_balance.saturating_sub(casino_mint.reserved_liability),
+                destination.to_account_info(),
+            )
+        },
+        None => {
+            let vault_balance = vault::balance(casino.vault_ref(), &ctx.accounts.vault)?;
+            let unreserved = vault_balance.saturating_sub(casino.reserved_liability);
+            let available = if ctx.accounts.liquidity_pool.data_is_empty() {
+                unreserved
+            } else {
+                casino.treasury_owed.min(unreserved)
+            };
+            (casino.vault_ref(), available, ctx.accounts.treasury.to_account_info())
+        },
+    };
+    require_gte!(available, amount, CasinoError::InsufficientVaultFunds);
+
+    vault::withdraw(
+        casino,
+        vault_ref,
+        &ctx.accounts.vault,
+        &ctx.accounts.mint,
+        &destination,
+        &treasury_owner,
+        &ctx.accounts.token_program,
+        &ctx.accounts.system_program,
+        amount,
+    )?;
+
+    let mint = match ctx.accounts.casino_mint.as_mut() {
+        Some(casino_mint) => {
+            casino_mint.treasury_owed = casino_mint.treasury_owed.saturating_sub(amount);
+            Some(casino_mint.mint)
+        },
+        None => {
+            let casino = &mut ctx.accounts.casino;
+            casino.treasury_owed = casino.treasury_owed.saturating_sub(amount);
+            None
+        },
+    };
+    let now = Clock::get()?.unix_timestamp;
+    emit!(TreasuryWithdrawn {
+        casino: ctx.accounts.casino.key(),
+        mint,
+        authority: ctx.accounts.authority.key(),
+        amount,
+        timestamp: now,
+    });
+
+    Ok(())
+}
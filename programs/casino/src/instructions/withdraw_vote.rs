@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, CloseAccount, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+use crate::errors::CasinoError;
+use crate::state::*;
+use crate::vault;
+
+#[derive(Accounts)]
+pub struct WithdrawVote<'info> {
+    #[account(
+        has_one = token_mint @ CasinoError::InvalidTokenMint,
+    )]
+    pub casino: Account<'info, Casino>,
+
+    #[account(
+        has_one = casino,
+        seeds = [GOVERNANCE_PROPOSAL_SEED, casino.key().as_ref(), proposal.proposer.as_ref(), &proposal.proposal_id.to_le_bytes()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, GovernanceProposal>,
+
+    #[account(
+        mut,
+        has_one = proposal,
+        has_one = voter,
+        close = voter,
+        seeds = [GOVERNANCE_VOTE_SEED, proposal.key().as_ref(), voter.key().as_ref()],
+        bump = vote_record.bump
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_ESCROW_SEED, vote_record.key().as_ref()],
+        bump,
+    )]
+    pub escrow: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub token_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        constraint = voter_token_account.owner == voter.key() @ CasinoError::InvalidTokenAccount,
+        constraint = voter_token_account.mint == token_mint.key() @ CasinoError::InvalidTokenMint,
+    )]
+    pub voter_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handler(ctx: Context<WithdrawVote>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    // Tokens stay locked for the whole voting period so they cannot be voted twice
+    require!(now >= ctx.accounts.proposal.voting_ends_at, CasinoError::OperationNotAllowed);
+
+    let proposal_key = ctx.accounts.proposal.key();
+    let voter_key = ctx.accounts.voter.key();
+    let seeds = &[
+        GOVERNANCE_VOTE_SEED,
+        proposal_key.as_ref(),
+        voter_key.as_ref(),
+        &[ctx.accounts.vote_record.bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.escrow.to_account_info(),
+                mint: ctx.accounts.token_mint.to_account_info(),
+                to: ctx.accounts.voter_token_account.to_account_info(),
+                authority: ctx.accounts.vote_record.to_account_info(),
+            },
+            signer,
+        ),
+        ctx.accounts.escrow.amount,
+        ctx.accounts.token_mint.decimals,
+    )?;
+
+    vault::harvest_withheld_fees(
+        &ctx.accounts.escrow.to_account_info(),
+        &ctx.accounts.token_mint.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+    )?;
+    token_interface::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.escrow.to_account_info(),
+            destination: ctx.accounts.voter.to_account_info(),
+            authority: ctx.accounts.vote_record.to_account_info(),
+        },
+        signer,
+    ))?;
+
+    Ok(())
+}
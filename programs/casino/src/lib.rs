@@ -1,45 +1,59 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer, Mint};
-use anchor_spl::associated_token::AssociatedToken;
-use switchboard_v2::AggregatorAccountData;
-use sha2::{Sha256, Digest};
 
-declare_id!("CasinoProgram11111111111111111111111111111");
+declare_id!("Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS");
 
 pub mod errors;
+pub mod events;
 pub mod instructions;
+pub mod oracle;
 pub mod state;
-pub mod utils;
+pub mod vault;
 
-use errors::*;
 use instructions::*;
 use state::*;
-use utils::*;
 
 #[program]
+#[allow(clippy::too_many_arguments)]
 pub mod casino {
     use super::*;
 
-    /// Initialize a new casino with configuration
+    /// Initialize a new casino with configuration, and its role table with every role
+    /// held by the authority
     pub fn initialize_casino(
         ctx: Context<InitializeCasino>,
         house_edge: u16,
         min_bet: u64,
         max_bet: u64,
         treasury_fee: u16,
+        governance: Option<GovernanceConfig>,
     ) -> Result<()> {
-        instructions::initialize_casino::handler(ctx, house_edge, min_bet, max_bet, treasury_fee)
+        instructions::initialize_casino::handler(ctx, house_edge, min_bet, max_bet, treasury_fee, governance)
     }
 
-    /// Create a new game session
+    /// Commit a server seed hash for a future game (resolver only)
+    pub fn commit_server_seed(ctx: Context<CommitServerSeed>, hash: [u8; 32]) -> Result<()> {
+        instructions::commit_server_seed::handler(ctx, hash)
+    }
+
+    /// Create a new game session against a server seed hash committed by the casino
     pub fn create_game(
         ctx: Context<CreateGame>,
+        session_id: u64,
         game_type: GameType,
         bet_amount: u64,
         prediction: Vec<u8>,
         client_seed: String,
+        side_bets: Vec<SideBet>,
     ) -> Result<()> {
-        instructions::create_game::handler(ctx, game_type, bet_amount, prediction, client_seed)
+        instructions::create_game::handler(
+            ctx,
+            session_id,
+            game_type,
+            bet_amount,
+            prediction,
+            client_seed,
+            side_bets,
+        )
     }
 
     /// Resolve a game using verifiable randomness
@@ -56,63 +70,385 @@ pub mod casino {
         instructions::claim_winnings::handler(ctx)
     }
 
-    /// Update casino configuration (admin only)
+    /// Cancel a game shortly after creation, refunding the stake minus the cancellation fee
+    pub fn cancel_game(ctx: Context<CancelGame>) -> Result<()> {
+        instructions::cancel_game::handler(ctx)
+    }
+
+    /// Pay an abandoned game its maximum payout after it expires (permissionless)
+    pub fn expire_game(ctx: Context<ExpireGame>) -> Result<()> {
+        instructions::expire_game::handler(ctx)
+    }
+
+    /// Close a settled game and return its rent to the player
+    pub fn close_game(ctx: Context<CloseGame>) -> Result<()> {
+        instructions::close_game::handler(ctx)
+    }
+
+    /// Open a player-vs-player coin flip duel
+    pub fn create_duel(
+        ctx: Context<CreateDuel>,
+        duel_id: u64,
+        stake: u64,
+        side: u8,
+        seed_hash: String,
+    ) -> Result<()> {
+        instructions::create_duel::handler(ctx, duel_id, stake, side, seed_hash)
+    }
+
+    /// Match an open duel
+    pub fn join_duel(ctx: Context<JoinDuel>, seed_hash: String) -> Result<()> {
+        instructions::join_duel::handler(ctx, seed_hash)
+    }
+
+    /// Reveal a committed client seed for a matched duel
+    pub fn reveal_duel_seed(ctx: Context<RevealDuelSeed>, client_seed: String) -> Result<()> {
+        instructions::reveal_duel_seed::handler(ctx, client_seed)
+    }
+
+    /// Settle a duel and pay the winner
+    pub fn settle_duel(ctx: Context<SettleDuel>) -> Result<()> {
+        instructions::settle_duel::handler(ctx)
+    }
+
+    /// Cancel an unmatched duel after the join timeout
+    pub fn cancel_duel(ctx: Context<CancelDuel>) -> Result<()> {
+        instructions::cancel_duel::handler(ctx)
+    }
+
+    /// Open a shared roulette round for group betting (admin only)
+    pub fn open_roulette_round(
+        ctx: Context<OpenRouletteRound>,
+        round_id: u64,
+        server_seed_hash: String,
+        betting_window: i64,
+    ) -> Result<()> {
+        instructions::open_roulette_round::handler(ctx, round_id, server_seed_hash, betting_window)
+    }
+
+    /// Place a bet into an open roulette round
+    pub fn place_roulette_bet(
+        ctx: Context<PlaceRouletteBet>,
+        kind: RouletteBetKind,
+        selection: u8,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::place_roulette_bet::handler(ctx, kind, selection, amount)
+    }
+
+    /// Spin a closed roulette round by revealing the committed server seed, mixed with
+    /// the hash of a slot after betting closed (admin only)
+    pub fn spin_roulette_round(ctx: Context<SpinRouletteRound>, server_seed: String) -> Result<()> {
+        instructions::spin_roulette_round::handler(ctx, server_seed)
+    }
+
+    /// Claim winnings for a roulette bet receipt
+    pub fn claim_roulette_bet(ctx: Context<ClaimRouletteBet>) -> Result<()> {
+        instructions::claim_roulette_bet::handler(ctx)
+    }
+
+    /// Refund a roulette bet, with its share of the round's maximum payout, once the
+    /// round has gone unspun past the deadline (permissionless)
+    pub fn refund_roulette_bet(ctx: Context<RefundRouletteBet>) -> Result<()> {
+        instructions::refund_roulette_bet::handler(ctx)
+    }
+
+    /// Create the progressive jackpot pool for a casino (admin only)
+    pub fn initialize_jackpot(
+        ctx: Context<InitializeJackpot>,
+        contribution_bps: u16,
+        payout_bps: u16,
+        trigger_odds: u32,
+    ) -> Result<()> {
+        instructions::initialize_jackpot::handler(ctx, contribution_bps, payout_bps, trigger_odds)
+    }
+
+    /// Claim the progressive jackpot for a game that hit the trigger
+    pub fn claim_jackpot(ctx: Context<ClaimJackpot>) -> Result<()> {
+        instructions::claim_jackpot::handler(ctx)
+    }
+
+    /// Update casino configuration (admin only). House edge, fee and max bet
+    /// increases have to be queued with `queue_config_change`
     pub fn update_casino_config(
         ctx: Context<UpdateCasinoConfig>,
         house_edge: Option<u16>,
         min_bet: Option<u64>,
         max_bet: Option<u64>,
         is_active: Option<bool>,
+        max_payout: Option<u64>,
+        cancellation_fee: Option<u16>,
+        unpause_delay: Option<i64>,
     ) -> Result<()> {
-        instructions::update_casino_config::handler(ctx, house_edge, min_bet, max_bet, is_active)
+        instructions::update_casino_config::handler(
+            ctx,
+            house_edge,
+            min_bet,
+            max_bet,
+            is_active,
+            max_payout,
+            cancellation_fee,
+            unpause_delay,
+        )
     }
 
-    /// Emergency pause (admin only)
-    pub fn emergency_pause(ctx: Context<EmergencyPause>) -> Result<()> {
-        instructions::emergency_pause::handler(ctx)
+    /// Accept an additional token mint with its own vault and limits (admin only)
+    pub fn add_casino_mint(
+        ctx: Context<AddCasinoMint>,
+        min_bet: u64,
+        max_bet: u64,
+        max_payout: u64,
+        house_edge: Option<u16>,
+        treasury_fee: Option<u16>,
+    ) -> Result<()> {
+        instructions::add_casino_mint::handler(ctx, min_bet, max_bet, max_payout, house_edge, treasury_fee)
     }
 
-    /// Withdraw treasury funds (admin only)
-    pub fn withdraw_treasury(
-        ctx: Context<WithdrawTreasury>,
-        amount: u64,
+    /// Update limits and fee overrides for an accepted mint (admin only). Override
+    /// and max bet increases have to be queued with `queue_config_change`
+    pub fn update_casino_mint(
+        ctx: Context<UpdateCasinoMint>,
+        min_bet: Option<u64>,
+        max_bet: Option<u64>,
+        max_payout: Option<u64>,
+        house_edge: Option<Option<u16>>,
+        treasury_fee: Option<Option<u16>>,
+        is_active: Option<bool>,
     ) -> Result<()> {
-        instructions::withdraw_treasury::handler(ctx, amount)
+        instructions::update_casino_mint::handler(ctx, min_bet, max_bet, max_payout, house_edge, treasury_fee, is_active)
+    }
+
+    /// Express bet limits in USD using a price source, or clear them by omitting the feed (admin only)
+    pub fn set_usd_limits(
+        ctx: Context<SetUsdLimits>,
+        source: PriceSourceKind,
+        min_bet_usd: u64,
+        max_bet_usd: u64,
+        max_staleness: u32,
+        max_confidence_bps: u16,
+    ) -> Result<()> {
+        instructions::set_usd_limits::handler(ctx, source, min_bet_usd, max_bet_usd, max_staleness, max_confidence_bps)
+    }
+
+    /// Track a TWAP for a token from its AMM pool reserves (admin only)
+    pub fn initialize_price_cache(
+        ctx: Context<InitializePriceCache>,
+        pool_kind: PoolKind,
+        window: i64,
+    ) -> Result<()> {
+        instructions::initialize_price_cache::handler(ctx, pool_kind, window)
+    }
+
+    /// Accumulate the current pool price into a price cache (permissionless crank)
+    pub fn update_price_cache(ctx: Context<UpdatePriceCache>) -> Result<()> {
+        instructions::update_price_cache::handler(ctx)
+    }
+
+    /// Open the vault to liquidity providers, issuing shares for the existing bankroll (admin only)
+    pub fn initialize_liquidity_pool(
+        ctx: Context<InitializeLiquidityPool>,
+        deposit_cap: u64,
+        cooldown_period: i64,
+        epoch_duration: i64,
+    ) -> Result<()> {
+        instructions::initialize_liquidity_pool::handler(ctx, deposit_cap, cooldown_period, epoch_duration)
+    }
+
+    /// Update the LP deposit cap, withdrawal cooldown and epoch length (admin only)
+    pub fn update_liquidity_pool(
+        ctx: Context<UpdateLiquidityPool>,
+        deposit_cap: Option<u64>,
+        cooldown_period: Option<i64>,
+        epoch_duration: Option<i64>,
+    ) -> Result<()> {
+        instructions::update_liquidity_pool::handler(ctx, deposit_cap, cooldown_period, epoch_duration)
+    }
+
+    /// Deposit into the vault in exchange for LP shares
+    pub fn deposit_liquidity(ctx: Context<DepositLiquidity>, amount: u64) -> Result<()> {
+        instructions::deposit_liquidity::handler(ctx, amount)
+    }
+
+    /// Escrow LP shares and start the withdrawal cooldown
+    pub fn request_liquidity_withdrawal(ctx: Context<RequestLiquidityWithdrawal>, shares: u64) -> Result<()> {
+        instructions::request_liquidity_withdrawal::handler(ctx, shares)
+    }
+
+    /// Return escrowed shares and drop a pending withdrawal request
+    pub fn cancel_liquidity_withdrawal(ctx: Context<CancelLiquidityWithdrawal>) -> Result<()> {
+        instructions::cancel_liquidity_withdrawal::handler(ctx)
+    }
+
+    /// Snapshot the pool value queued withdrawals are priced at (permissionless crank)
+    pub fn settle_liquidity_epoch(ctx: Context<SettleLiquidityEpoch>) -> Result<()> {
+        instructions::settle_liquidity_epoch::handler(ctx)
     }
 
-    /// Initialize player profile
-    pub fn initialize_player(ctx: Context<InitializePlayer>) -> Result<()> {
-        instructions::initialize_player::handler(ctx)
+    /// Redeem a matured withdrawal request at the latest epoch price
+    pub fn execute_liquidity_withdrawal(ctx: Context<ExecuteLiquidityWithdrawal>) -> Result<()> {
+        instructions::execute_liquidity_withdrawal::handler(ctx)
     }
 
-    /// Update player statistics
-    pub fn update_player_stats(
-        ctx: Context<UpdatePlayerStats>,
-        games_played: u64,
-        total_wagered: u64,
-        total_won: u64,
+    /// Configure the dynamic max bet and the drawdown circuit breaker of the primary
+    /// mint, or of `casino_mint` when passed (admin only)
+    pub fn update_risk_limits(
+        ctx: Context<UpdateRiskLimits>,
+        kelly_fraction: Option<u16>,
+        max_drawdown: Option<u64>,
+        drawdown_window: Option<i64>,
+        max_payout_share: Option<u16>,
     ) -> Result<()> {
-        instructions::update_player_stats::handler(ctx, games_played, total_wagered, total_won)
+        instructions::update_risk_limits::handler(ctx, kelly_fraction, max_drawdown, drawdown_window, max_payout_share)
+    }
+
+    /// Re-enable betting after the primary mint's, or `casino_mint`'s, circuit
+    /// breaker tripped (admin only)
+    pub fn reset_circuit_breaker(ctx: Context<ResetCircuitBreaker>) -> Result<()> {
+        instructions::reset_circuit_breaker::handler(ctx)
+    }
+
+    /// Emergency pause, starting the unpause delay (pauser only)
+    pub fn emergency_pause(ctx: Context<EmergencyPause>, reason: PauseReason) -> Result<()> {
+        instructions::emergency_pause::handler(ctx, reason)
+    }
+
+    /// Resume after the unpause delay, optionally allowing only resolve and claim flows first (admin only)
+    pub fn unpause_casino(ctx: Context<UnpauseCasino>, reason: PauseReason, resolve_only: bool) -> Result<()> {
+        instructions::unpause_casino::handler(ctx, reason, resolve_only)
     }
 
-    /// Create tournament
-    pub fn create_tournament(
-        ctx: Context<CreateTournament>,
-        entry_fee: u64,
-        max_players: u32,
-        start_time: i64,
-        duration: i64,
+    /// Announce a maintenance window, or clear it with None (admin only)
+    pub fn set_maintenance_window(
+        ctx: Context<SetMaintenanceWindow>,
+        window: Option<MaintenanceWindow>,
     ) -> Result<()> {
-        instructions::create_tournament::handler(ctx, entry_fee, max_players, start_time, duration)
+        instructions::set_maintenance_window::handler(ctx, window)
     }
 
-    /// Join tournament
-    pub fn join_tournament(ctx: Context<JoinTournament>) -> Result<()> {
-        instructions::join_tournament::handler(ctx)
+    /// Propose a new holder for a role, or for the casino authority when `role` is
+    /// None (authority only)
+    pub fn propose_authority(
+        ctx: Context<ProposeAuthority>,
+        role: Option<Role>,
+        proposed: Pubkey,
+    ) -> Result<()> {
+        instructions::propose_authority::handler(ctx, role, proposed)
     }
 
-    /// Finalize tournament
-    pub fn finalize_tournament(ctx: Context<FinalizeTournament>) -> Result<()> {
-        instructions::finalize_tournament::handler(ctx)
+    /// Take over a role or the casino authority proposed to the signer
+    pub fn accept_authority(ctx: Context<AcceptAuthority>, role: Option<Role>) -> Result<()> {
+        instructions::accept_authority::handler(ctx, role)
     }
+
+    /// Withdraw a pending role or authority proposal (authority only)
+    pub fn cancel_authority_transfer(ctx: Context<CancelAuthorityTransfer>, role: Option<Role>) -> Result<()> {
+        instructions::cancel_authority_transfer::handler(ctx, role)
+    }
+
+    /// Queue a house edge, fee or max bet change for the casino, or for a mint's overrides
+    /// when `casino_mint` is passed (Some(None) clears an override), taking effect
+    /// after the timelock
+    pub fn queue_config_change(
+        ctx: Context<QueueConfigChange>,
+        house_edge: Option<Option<u16>>,
+        treasury_fee: Option<Option<u16>>,
+        cancellation_fee: Option<u16>,
+        max_bet: Option<u64>,
+        effective_at: i64,
+    ) -> Result<()> {
+        instructions::queue_config_change::handler(ctx, house_edge, treasury_fee, cancellation_fee, max_bet, effective_at)
+    }
+
+    /// Drop a queued config change before it takes effect
+    pub fn cancel_config_change(ctx: Context<CancelConfigChange>) -> Result<()> {
+        instructions::cancel_config_change::handler(ctx)
+    }
+
+    /// Apply a queued config change whose timelock has passed (permissionless)
+    pub fn apply_config_change(ctx: Context<ApplyConfigChange>) -> Result<()> {
+        instructions::apply_config_change::handler(ctx)
+    }
+
+    /// Set up the casino's M-of-N signer set (authority only)
+    pub fn create_multisig(ctx: Context<CreateMultisig>, signers: Vec<Pubkey>, threshold: u8) -> Result<()> {
+        instructions::create_multisig::handler(ctx, signers, threshold)
+    }
+
+    /// Propose an admin action to the multisig, valid for `ttl` seconds
+    pub fn create_proposal(ctx: Context<CreateProposal>, action: ProposalAction, ttl: i64) -> Result<()> {
+        instructions::create_proposal::handler(ctx, action, ttl)
+    }
+
+    /// Approve a proposal as one of the multisig signers
+    pub fn approve_proposal(ctx: Context<ApproveProposal>) -> Result<()> {
+        instructions::approve_proposal::handler(ctx)
+    }
+
+    /// Carry out a proposal that reached its threshold (permissionless)
+    pub fn execute_proposal(ctx: Context<ExecuteProposal>) -> Result<()> {
+        instructions::execute_proposal::handler(ctx)
+    }
+
+    /// Call the casino instruction an approved `Invoke` proposal names, signed by
+    /// the multisig (permissionless)
+    pub fn invoke_proposal<'info>(ctx: Context<'_, '_, '_, 'info, InvokeProposal<'info>>) -> Result<()> {
+        instructions::invoke_proposal::handler(ctx)
+    }
+
+    /// Withdraw an open proposal (its proposer only)
+    pub fn cancel_proposal(ctx: Context<CancelProposal>) -> Result<()> {
+        instructions::cancel_proposal::handler(ctx)
+    }
+
+    /// Return the rent of an executed, cancelled, expired or superseded proposal to its proposer
+    pub fn close_proposal(ctx: Context<CloseProposal>) -> Result<()> {
+        instructions::close_proposal::handler(ctx)
+    }
+
+    /// Put a parameter change to a vote of token holders
+    pub fn create_governance_proposal(
+        ctx: Context<CreateGovernanceProposal>,
+        proposal_id: u64,
+        update: ParameterUpdate,
+        voting_period: i64,
+    ) -> Result<()> {
+        instructions::create_governance_proposal::handler(ctx, proposal_id, update, voting_period)
+    }
+
+    /// Vote on a governance proposal by escrowing tokens until voting ends
+    pub fn cast_vote(ctx: Context<CastVote>, amount: u64, support: bool) -> Result<()> {
+        instructions::cast_vote::handler(ctx, amount, support)
+    }
+
+    /// Tally a governance proposal after voting ends and apply it if it passed
+    /// (permissionless)
+    pub fn execute_governance_proposal(ctx: Context<ExecuteGovernanceProposal>) -> Result<()> {
+        instructions::execute_governance_proposal::handler(ctx)
+    }
+
+    /// Reclaim the tokens escrowed for a vote once voting has ended
+    pub fn withdraw_vote(ctx: Context<WithdrawVote>) -> Result<()> {
+        instructions::withdraw_vote::handler(ctx)
+    }
+
+    /// Bar a player from placing new bets (compliance only)
+    pub fn ban_player(ctx: Context<BanPlayer>, player: Pubkey) -> Result<()> {
+        instructions::ban_player::handler(ctx, player)
+    }
+
+    /// Lift a player's ban (compliance only)
+    pub fn unban_player(ctx: Context<UnbanPlayer>) -> Result<()> {
+        instructions::unban_player::handler(ctx)
+    }
+
+    /// Sweep accrued treasury fees, or any free bankroll before LPs join, to the
+    /// treasury; with `casino_mint`, from that mint's vault to the treasury owner's
+    /// token account for it (treasurer only)
+    pub fn withdraw_treasury(
+        ctx: Context<WithdrawTreasury>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::withdraw_treasury::handler(ctx, amount)
+    }
+
 }
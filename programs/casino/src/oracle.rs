@@ -0,0 +1,321 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use anchor_spl::token_interface::TokenAccount;
+use switchboard_v2::{AggregatorAccountData, SwitchboardDecimal, SWITCHBOARD_PROGRAM_ID};
+
+use crate::errors::CasinoError;
+use crate::state::{Casino, PoolKind, PriceCache, PriceSourceKind, UsdLimits, BASIS_POINTS, USD_DECIMALS};
+
+// Token valuation in USD.
+//
+// Prices are USD per whole token in fixed point with `PRICE_DECIMALS` decimals, so
+// a bet of `amount` base units is worth `amount * price / 10^(decimals + PRICE_DECIMALS)`
+// USD. Two sources are supported: a Switchboard aggregator, or a `PriceCache`
+// holding a TWAP cranked from an AMM pool for tokens without an oracle. Sources
+// are read as-is: staleness and confidence are checked on every use. The source
+// configured in a casino's `UsdLimits` also values its USD stats and jackpot wins.
+
+/// Fixed-point decimals of a `TokenPrice`
+pub const PRICE_DECIMALS: u32 = 12;
+
+/// USD price of one whole token, scaled by 10^PRICE_DECIMALS
+#[derive(Clone, Copy)]
+pub struct TokenPrice {
+    pub price: u128,
+}
+
+impl TokenPrice {
+    /// Value of `amount` base units of a mint with `decimals` decimals, in micro-USD
+    pub fn usd_value(&self, amount: u64, decimals: u8) -> Result<u64> {
+        let value = (amount as u128)
+            .checked_mul(self.price)
+            .ok_or(CasinoError::ArithmeticOverflow)?
+            / pow10(decimals as u32 + PRICE_DECIMALS - USD_DECIMALS)?;
+        u64::try_from(value).map_err(|_| CasinoError::ArithmeticOverflow.into())
+    }
+}
+
+/// Reads the USD price of a token from whichever source backs `account`
+pub fn token_price(
+    source: PriceSourceKind,
+    account: &AccountInfo,
+    now: i64,
+    max_staleness: u32,
+    max_confidence_bps: u16,
+) -> Result<TokenPrice> {
+    match source {
+        PriceSourceKind::Switchboard => {
+            require_keys_eq!(*account.owner, SWITCHBOARD_PROGRAM_ID, CasinoError::InvalidAccountOwner);
+
+            // Borrow the aggregator in place, it is too large to copy onto the stack
+            let data = account.try_borrow_data()?;
+            require!(
+                data.len() >= 8 + std::mem::size_of::<AggregatorAccountData>() &&
+                    data[..8] == AggregatorAccountData::DISCRIMINATOR,
+                CasinoError::OraclePriceFeedInvalid
+            );
+            let feed = bytemuck::try_from_bytes::<AggregatorAccountData>(
+                &data[8..8 + std::mem::size_of::<AggregatorAccountData>()],
+            )
+            .map_err(|_| CasinoError::OraclePriceFeedInvalid)?;
+            switchboard_price(feed, now, max_staleness, max_confidence_bps)
+        },
+        PriceSourceKind::AmmTwap => {
+            let cache = load_price_cache(account)?;
+            amm_twap_price(&cache, now, max_staleness, max_confidence_bps)
+        },
+    }
+}
+
+/// Reads the latest confirmed round of a Switchboard aggregator
+pub fn switchboard_price(
+    feed: &AggregatorAccountData,
+    now: i64,
+    max_staleness: u32,
+    max_confidence_bps: u16,
+) -> Result<TokenPrice> {
+    let round = &feed.latest_confirmed_round;
+    require!(
+        now.saturating_sub(round.round_open_timestamp) <= max_staleness as i64,
+        CasinoError::OraclePriceFeedStale
+    );
+
+    let result = feed.get_result().map_err(|_| error!(CasinoError::OraclePriceFeedInvalid))?;
+    let price = to_price(result)?;
+    require!(price > 0, CasinoError::OraclePriceFeedInvalid);
+
+    let std_deviation = to_price(round.std_deviation)?;
+    require!(
+        std_deviation.saturating_mul(BASIS_POINTS as u128) <= price.saturating_mul(max_confidence_bps as u128),
+        CasinoError::OraclePriceFeedInvalid
+    );
+
+    Ok(TokenPrice { price })
+}
+
+/// Reads the TWAP of a price cache, rejecting it when the crank has stalled or the
+/// spot price has drifted too far from the average (a sign of pool manipulation)
+pub fn amm_twap_price(
+    cache: &PriceCache,
+    now: i64,
+    max_staleness: u32,
+    max_deviation_bps: u16,
+) -> Result<TokenPrice> {
+    require!(
+        cache.last_updated > 0 && now.saturating_sub(cache.last_updated) <= max_staleness as i64,
+        CasinoError::OraclePriceFeedStale
+    );
+
+    let price = cache.twap(now)?;
+    require!(price > 0, CasinoError::OraclePriceFeedInvalid);
+
+    let deviation = cache.spot_price.abs_diff(price);
+    require!(
+        deviation.saturating_mul(BASIS_POINTS as u128) <= price.saturating_mul(max_deviation_bps as u128),
+        CasinoError::OraclePriceFeedInvalid
+    );
+
+    Ok(TokenPrice { price })
+}
+
+/// Deserializes a program-owned price cache
+pub fn load_price_cache(account: &AccountInfo) -> Result<PriceCache> {
+    require_keys_eq!(*account.owner, crate::ID, CasinoError::InvalidAccountOwner);
+    PriceCache::try_deserialize(&mut &account.try_borrow_data()?[..])
+}
+
+/// Reads the base and quote reserves of the pool backing a price cache
+pub fn pool_reserves(cache: &PriceCache, base_reserve: &AccountInfo, quote_reserve: &AccountInfo) -> Result<(u64, u64)> {
+    require_keys_eq!(base_reserve.key(), cache.base_reserve, CasinoError::InvalidTokenAccount);
+    require_keys_eq!(quote_reserve.key(), cache.quote_reserve, CasinoError::InvalidTokenAccount);
+
+    match cache.pool_kind {
+        PoolKind::TokenReserves => Ok((
+            token_reserve(base_reserve, &cache.mint)?,
+            token_reserve(quote_reserve, &cache.quote_mint)?,
+        )),
+        PoolKind::Mock => mock_pool_reserves(base_reserve),
+    }
+}
+
+/// Checks a bet in the casino's primary mint against its USD limits when they are
+/// configured, or its raw token limits otherwise. Returns the bet's USD value
+/// when it was priced.
+pub fn validate_bet_amount(
+    casino: &Casino,
+    amount: u64,
+    decimals: u8,
+    price_feed: Option<&AccountInfo>,
+) -> Result<Option<u64>> {
+    let Some(limits) = casino.usd_limits else {
+        casino.validate_bet_amount(amount)?;
+        return Ok(None);
+    };
+
+    let usd_value = casino_price(&limits, price_feed)?.usd_value(amount, decimals)?;
+    limits.validate_bet_value(usd_value)?;
+    Ok(Some(usd_value))
+}
+
+/// USD value of `amount` of the casino's primary mint for stats, or None when the
+/// casino has no price source or it cannot be read right now, so that payouts
+/// never wait on a price
+pub fn stats_usd_value(
+    casino: &Casino,
+    amount: u64,
+    decimals: u8,
+    price_feed: Option<&AccountInfo>,
+) -> Option<u64> {
+    let limits = casino.usd_limits?;
+    casino_price(&limits, price_feed)
+        .and_then(|price| price.usd_value(amount, decimals))
+        .ok()
+}
+
+/// Reads the price of the casino's primary mint from its configured source
+fn casino_price(limits: &UsdLimits, price_feed: Option<&AccountInfo>) -> Result<TokenPrice> {
+    let price_feed = price_feed.ok_or(CasinoError::OraclePriceFeedInvalid)?;
+    require_keys_eq!(price_feed.key(), limits.price_feed, CasinoError::OraclePriceFeedInvalid);
+
+    token_price(
+        limits.source,
+        price_feed,
+        Clock::get()?.unix_timestamp,
+        limits.max_staleness,
+        limits.max_confidence_bps,
+    )
+}
+
+fn token_reserve(account: &AccountInfo, mint: &Pubkey) -> Result<u64> {
+    require!(
+        *account.owner == anchor_spl::token::ID || *account.owner == anchor_spl::token_2022::ID,
+        CasinoError::InvalidAccountOwner
+    );
+
+    let reserve = TokenAccount::try_deserialize(&mut &account.try_borrow_data()?[..])?;
+    require_keys_eq!(reserve.mint, *mint, CasinoError::InvalidTokenMint);
+    Ok(reserve.amount)
+}
+
+#[cfg(feature = "mock-pool")]
+fn mock_pool_reserves(pool: &AccountInfo) -> Result<(u64, u64)> {
+    let pool = crate::state::MockPool::deserialize(&mut &pool.try_borrow_data()?[..])?;
+    Ok((pool.base_reserve, pool.quote_reserve))
+}
+
+#[cfg(not(feature = "mock-pool"))]
+fn mock_pool_reserves(_pool: &AccountInfo) -> Result<(u64, u64)> {
+    err!(CasinoError::OraclePriceFeedInvalid)
+}
+
+fn to_price(value: SwitchboardDecimal) -> Result<u128> {
+    let mantissa = u128::try_from(value.mantissa).map_err(|_| CasinoError::OraclePriceFeedInvalid)?;
+    if value.scale > PRICE_DECIMALS {
+        Ok(mantissa / pow10(value.scale - PRICE_DECIMALS)?)
+    } else {
+        mantissa
+            .checked_mul(pow10(PRICE_DECIMALS - value.scale)?)
+            .ok_or(CasinoError::ArithmeticOverflow.into())
+    }
+}
+
+fn pow10(exponent: u32) -> Result<u128> {
+    10u128.checked_pow(exponent).ok_or(CasinoError::ArithmeticOverflow.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{PriceObservation, TWAP_OBSERVATIONS};
+
+    const ONE: u128 = 1_000_000_000_000; // $1 in PRICE_DECIMALS
+
+    /// A cache cranked at `price` once per observation interval for a whole window, up to `now`
+    fn settled_cache(price: u128, now: i64) -> PriceCache {
+        let mut cache = PriceCache {
+            casino: Pubkey::new_unique(),
+            mint: Pubkey::new_unique(),
+            quote_mint: Pubkey::new_unique(),
+            pool_kind: PoolKind::Mock,
+            base_reserve: Pubkey::new_unique(),
+            quote_reserve: Pubkey::new_unique(),
+            base_decimals: 6,
+            quote_decimals: 6,
+            window: 600,
+            spot_price: 0,
+            cumulative_price: 0,
+            last_updated: 0,
+            observations: [PriceObservation::default(); TWAP_OBSERVATIONS],
+            observation_index: 0,
+            observation_count: 0,
+            created_at: 0,
+            bump: 255,
+        };
+        for at in (now - 600..=now).step_by(75) {
+            cache.record(price, at).unwrap();
+        }
+        cache
+    }
+
+    #[test]
+    fn twap_price_accepts_spot_within_deviation() {
+        let mut cache = settled_cache(2 * ONE, 10_000);
+        cache.spot_price = 2 * ONE + ONE / 10; // 5% above the average
+
+        assert_eq!(amm_twap_price(&cache, 10_000, 60, 500).unwrap().price, 2 * ONE);
+    }
+
+    #[test]
+    fn twap_price_rejects_manipulated_spot() {
+        let mut cache = settled_cache(2 * ONE, 10_000);
+        cache.spot_price = 3 * ONE; // pumped 50% above the average
+
+        assert!(amm_twap_price(&cache, 10_000, 60, 1_000).is_err());
+        cache.spot_price = ONE; // dumped 50% below it
+        assert!(amm_twap_price(&cache, 10_000, 60, 1_000).is_err());
+        assert!(amm_twap_price(&cache, 10_000, 60, 5_000).is_ok());
+    }
+
+    #[test]
+    fn twap_price_rejects_stalled_crank() {
+        let cache = settled_cache(2 * ONE, 10_000);
+
+        assert!(amm_twap_price(&cache, 10_060, 60, 500).is_ok());
+        assert!(amm_twap_price(&cache, 10_061, 60, 500).is_err());
+    }
+
+    #[test]
+    fn usd_value_scales_by_mint_decimals() {
+        let price = TokenPrice { price: 2 * ONE };
+
+        // 1.5 tokens of a 6-decimal mint at $2 is $3, in micro-USD
+        assert_eq!(price.usd_value(1_500_000, 6).unwrap(), 3_000_000);
+        assert_eq!(price.usd_value(1_500_000_000, 9).unwrap(), 3_000_000);
+    }
+
+    #[cfg(feature = "mock-pool")]
+    #[test]
+    fn mock_pool_cranks_into_twap() {
+        use crate::state::MockPool;
+
+        let mut cache = settled_cache(ONE, 10_000);
+        let owner = crate::ID;
+        let mut lamports = 0;
+        let mut data = MockPool { base_reserve: 1_000_000_000, quote_reserve: 3_000_000_000 }
+            .try_to_vec()
+            .unwrap();
+        let pool = AccountInfo::new(&cache.base_reserve, false, false, &mut lamports, &mut data, &owner, false, 0);
+        let quote_reserve = pool.clone();
+        cache.quote_reserve = cache.base_reserve;
+
+        let (base, quote) = pool_reserves(&cache, &pool, &quote_reserve).unwrap();
+        let spot = cache.spot_price(base, quote).unwrap();
+        assert_eq!(spot, 3 * ONE);
+
+        // Tripling the pool price moves the average only as fast as time passes
+        cache.record(spot, 10_300).unwrap();
+        assert_eq!(cache.twap(10_300).unwrap(), ONE);
+        assert_eq!(cache.twap(10_600).unwrap(), 2 * ONE);
+        assert!(amm_twap_price(&cache, 10_600, 600, 1_000).is_err());
+    }
+}
@@ -0,0 +1,238 @@
+use anchor_lang::prelude::*;
+
+// Baccarat paytable (total return in basis points)
+pub const BACCARAT_PLAYER_PAYOUT: u64 = 20000; // 2x, even money
+pub const BACCARAT_BANKER_PAYOUT: u64 = 19500; // 1.95x, even money less 5% commission
+pub const BACCARAT_TIE_PAYOUT: u64 = 90000; // 9x, 8 to 1
+pub const BACCARAT_PAIR_PAYOUT: u64 = 120000; // 12x, 11 to 1
+
+// Main bet predictions
+pub const BACCARAT_PLAYER: u8 = 0;
+pub const BACCARAT_BANKER: u8 = 1;
+pub const BACCARAT_TIE: u8 = 2;
+
+/// Decks in the baccarat shoe
+pub const SHOE_DECKS: usize = 8;
+const SHOE_SIZE: usize = 52 * SHOE_DECKS;
+
+/// A card dealt from the shoe: rank 0-12 (ace low), suit 0-3
+#[derive(Clone, Copy)]
+struct Card {
+    rank: u8,
+}
+
+impl Card {
+    fn from_index(index: usize) -> Self {
+        Card { rank: (index % 13) as u8 }
+    }
+
+    /// Ace counts one, tens and faces count zero
+    fn value(&self) -> u8 {
+        if self.rank < 9 { self.rank + 1 } else { 0 }
+    }
+}
+
+/// Deals the six cards a coup can use from a single shoe without replacement, in
+/// dealing order: player, banker, player, banker, then the third cards.
+///
+/// Each draw takes a uniform index into the cards left using rejection sampling on
+/// 16-bit words of `outcome`, which is re-hashed whenever it runs out.
+fn deal(outcome: &[u8]) -> Result<[Card; 6]> {
+    use sha2::{Sha256, Digest};
+
+    require!(outcome.len() >= 2, crate::errors::CasinoError::InvalidRandomnessSource);
+
+    let mut shoe: Vec<usize> = (0..SHOE_SIZE).collect();
+    let mut stream = outcome.to_vec();
+    let mut position = 0;
+    let mut dealt = [0usize; 6];
+    for card in dealt.iter_mut() {
+        let remaining = shoe.len();
+        let limit = u16::MAX as usize + 1 - (u16::MAX as usize + 1) % remaining;
+        let index = loop {
+            if position + 2 > stream.len() {
+                stream = Sha256::digest(&stream).to_vec();
+                position = 0;
+            }
+            let word = u16::from_le_bytes([stream[position], stream[position + 1]]) as usize;
+            position += 2;
+            if word < limit {
+                break word % remaining;
+            }
+        };
+        *card = shoe.swap_remove(index);
+    }
+
+    Ok(dealt.map(Card::from_index))
+}
+
+/// Final player and banker totals under the standard drawing rules, from card
+/// values in dealing order
+fn totals(values: [u8; 6]) -> (u8, u8) {
+    let mut player = (values[0] + values[2]) % 10;
+    let mut banker = (values[1] + values[3]) % 10;
+    if player >= 8 || banker >= 8 {
+        return (player, banker); // natural
+    }
+
+    let mut next = 4;
+    let player_third = if player <= 5 {
+        player = (player + values[next]) % 10;
+        next += 1;
+        Some(values[4])
+    } else {
+        None
+    };
+    let banker_draws = match player_third {
+        None => banker <= 5,
+        Some(third) => match banker {
+            0..=2 => true,
+            3 => third != 8,
+            4 => (2..=7).contains(&third),
+            5 => (4..=7).contains(&third),
+            6 => (6..=7).contains(&third),
+            _ => false,
+        },
+    };
+    if banker_draws {
+        banker = (banker + values[next]) % 10;
+    }
+    (player, banker)
+}
+
+/// Result of a coup dealt from a game outcome
+pub struct Coup {
+    pub player_total: u8,
+    pub banker_total: u8,
+    /// The player's first two cards share a rank
+    pub player_pair: bool,
+    /// The banker's first two cards share a rank
+    pub banker_pair: bool,
+}
+
+impl Coup {
+    pub fn deal(outcome: &[u8]) -> Result<Self> {
+        let cards = deal(outcome)?;
+        let (player_total, banker_total) = totals(cards.map(|card| card.value()));
+        Ok(Coup {
+            player_total,
+            banker_total,
+            player_pair: cards[0].rank == cards[2].rank,
+            banker_pair: cards[1].rank == cards[3].rank,
+        })
+    }
+
+    /// Multiplier of a main bet on `prediction`; player and banker bets push on a tie
+    pub fn multiplier(&self, prediction: u8) -> u64 {
+        multiplier(prediction, self.player_total, self.banker_total)
+    }
+}
+
+fn multiplier(prediction: u8, player_total: u8, banker_total: u8) -> u64 {
+    match (prediction, player_total.cmp(&banker_total)) {
+        (BACCARAT_TIE, std::cmp::Ordering::Equal) => BACCARAT_TIE_PAYOUT,
+        (BACCARAT_TIE, _) => 0,
+        (_, std::cmp::Ordering::Equal) => super::BASIS_POINTS,
+        (BACCARAT_PLAYER, std::cmp::Ordering::Greater) => BACCARAT_PLAYER_PAYOUT,
+        (BACCARAT_BANKER, std::cmp::Ordering::Less) => BACCARAT_BANKER_PAYOUT,
+        _ => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::BASIS_POINTS;
+
+    /// Exact return to player of a main bet over every six-card deal from the shoe,
+    /// by point value, weighted by how many shoe orderings produce it
+    fn main_bet_rtp(prediction: u8) -> f64 {
+        // Tens and faces all count zero
+        let mut counts = [32 * SHOE_DECKS as u128 / 8; 10];
+        counts[0] *= 4;
+
+        let mut total_return = 0u128;
+        let mut deals = 0u128;
+        let mut values = [0u8; 6];
+        fn visit(
+            depth: usize,
+            weight: u128,
+            counts: &mut [u128; 10],
+            values: &mut [u8; 6],
+            prediction: u8,
+            total_return: &mut u128,
+            deals: &mut u128,
+        ) {
+            if depth == 6 {
+                let (player, banker) = totals(*values);
+                *total_return += weight * multiplier(prediction, player, banker) as u128;
+                *deals += weight;
+                return;
+            }
+            for value in 0..10 {
+                if counts[value] == 0 {
+                    continue;
+                }
+                let count = counts[value];
+                counts[value] -= 1;
+                values[depth] = value as u8;
+                visit(depth + 1, weight * count, counts, values, prediction, total_return, deals);
+                counts[value] += 1;
+            }
+        }
+        visit(0, 1, &mut counts, &mut values, prediction, &mut total_return, &mut deals);
+        total_return as f64 / deals as f64 / BASIS_POINTS as f64
+    }
+
+    #[test]
+    fn banker_rtp() {
+        let rtp = main_bet_rtp(BACCARAT_BANKER);
+        assert!((rtp - 0.98942).abs() < 1e-5, "{rtp}");
+    }
+
+    #[test]
+    fn player_rtp() {
+        let rtp = main_bet_rtp(BACCARAT_PLAYER);
+        assert!((rtp - 0.98765).abs() < 1e-5, "{rtp}");
+    }
+
+    #[test]
+    fn tie_rtp() {
+        let rtp = main_bet_rtp(BACCARAT_TIE);
+        assert!((rtp - 0.85641).abs() < 1e-5, "{rtp}");
+    }
+
+    #[test]
+    fn drawing_rules() {
+        // Naturals stand
+        assert_eq!(totals([8, 0, 0, 5, 9, 9]), (8, 5));
+        // Player stands on 6, banker draws on 5
+        assert_eq!(totals([3, 2, 3, 3, 4, 9]), (6, 9));
+        // Banker on 3 stands against a third card of 8
+        assert_eq!(totals([1, 1, 1, 2, 8, 9]), (0, 3));
+        // Banker on 6 draws against a third card of 7
+        assert_eq!(totals([2, 3, 2, 3, 7, 2]), (1, 8));
+    }
+
+    #[test]
+    fn deal_draws_without_replacement() {
+        use sha2::{Sha256, Digest};
+
+        let mut pairs = 0;
+        for seed in 0u32..20_000 {
+            if Coup::deal(&Sha256::digest(seed.to_le_bytes())).unwrap().player_pair {
+                pairs += 1;
+            }
+        }
+        // 31 of the 415 cards left pair the first card
+        let frequency = pairs as f64 / 20_000.0;
+        assert!((frequency - 31.0 / 415.0).abs() < 0.006, "{frequency}");
+    }
+
+    #[test]
+    fn deal_refills_short_outcomes() {
+        // Two bytes are only enough for one draw, the rest comes from re-hashing
+        assert!(Coup::deal(&[0xff, 0xff]).is_ok());
+        assert!(Coup::deal(&[0x01]).is_err());
+    }
+}
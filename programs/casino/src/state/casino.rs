@@ -1,15 +1,20 @@
 use anchor_lang::prelude::*;
-use super::{CasinoStats, BASIS_POINTS};
+use super::{
+    CasinoStats, CircuitBreaker, GovernanceConfig, MaintenanceWindow, ParameterUpdate, PauseReason, PauseRecord,
+    PendingConfigChange, UsdLimits, VaultRef, BASIS_POINTS, CONFIG_TIMELOCK,
+};
 
 #[account]
 pub struct Casino {
     /// Authority that can update casino settings
     pub authority: Pubkey,
+    /// Proposed new authority, awaiting its acceptance
+    pub pending_authority: Option<Pubkey>,
     /// Token mint for the casino (SOL or SPL token)
     pub token_mint: Pubkey,
     /// Treasury account for collecting fees
     pub treasury: Pubkey,
-    /// Vault account for holding game funds
+    /// Vault account for holding game funds (system-owned PDA for native SOL)
     pub vault: Pubkey,
     /// House edge in basis points (e.g., 200 = 2%)
     pub house_edge: u16,
@@ -19,10 +24,39 @@ pub struct Casino {
     pub max_bet: u64,
     /// Treasury fee in basis points (e.g., 100 = 1%)
     pub treasury_fee: u16,
+    /// Maximum payout of a single game in token units (0 = bounded by vault only)
+    pub max_payout: u64,
+    /// Potential payouts currently reserved against the vault by open games
+    pub reserved_liability: u64,
+    /// Treasury fees accrued in the vault but not yet withdrawn; never LP capital
+    pub treasury_owed: u64,
+    /// Fee kept when a player cancels a game, in basis points; never below
+    /// `MIN_CANCELLATION_FEE`
+    pub cancellation_fee: u16,
+    /// Queued increase of the house edge or fees, visible until it takes effect
+    pub pending_config: Option<PendingConfigChange>,
+    /// Bet limits in USD, replacing `min_bet`/`max_bet` when set
+    pub usd_limits: Option<UsdLimits>,
+    /// Share of free vault balance a single bet may put at risk, in basis points
+    /// (0 = static `max_bet` only)
+    pub kelly_fraction: u16,
+    /// Drawdown circuit breaker refusing new bets after heavy losses
+    pub circuit_breaker: CircuitBreaker,
     /// Whether the casino is currently active
     pub is_active: bool,
     /// Whether the casino is paused (emergency)
     pub is_paused: bool,
+    /// Whether only resolve, claim and refund flows are allowed after a pause
+    pub resolve_only: bool,
+    /// Minimum time between pausing and unpausing, in seconds; a change only applies
+    /// to later pauses
+    pub unpause_delay: i64,
+    /// Who last paused and resumed the casino, and why
+    pub pause_record: PauseRecord,
+    /// Scheduled maintenance during which new bets are refused
+    pub maintenance_window: Option<MaintenanceWindow>,
+    /// Token-holder governance over parameters (None = admin only)
+    pub governance: Option<GovernanceConfig>,
     /// Casino statistics
     pub stats: CasinoStats,
     /// Timestamp when casino was created
@@ -31,11 +65,14 @@ pub struct Casino {
     pub updated_at: i64,
     /// Bump seed for PDA
     pub bump: u8,
+    /// Bump seed for the native SOL vault PDA
+    pub vault_bump: u8,
 }
 
 impl Casino {
     pub const LEN: usize = 8 + // discriminator
         32 + // authority
+        1 + 32 + // pending_authority (optional)
         32 + // token_mint
         32 + // treasury
         32 + // vault
@@ -43,12 +80,26 @@ impl Casino {
         8 + // min_bet
         8 + // max_bet
         2 + // treasury_fee
+        8 + // max_payout
+        8 + // reserved_liability
+        8 + // treasury_owed
+        2 + // cancellation_fee
+        1 + PendingConfigChange::LEN + // pending_config (optional)
+        1 + UsdLimits::LEN + // usd_limits (optional)
+        2 + // kelly_fraction
+        CircuitBreaker::LEN + // circuit_breaker
         1 + // is_active
         1 + // is_paused
-        (8 * 8 + 4) + // stats (8 u64s + 1 u32)
+        1 + // resolve_only
+        8 + // unpause_delay
+        PauseRecord::LEN + // pause_record
+        1 + MaintenanceWindow::LEN + // maintenance_window (optional)
+        1 + GovernanceConfig::LEN + // governance (optional)
+        (11 * 8 + 4) + // stats (11 u64s + 1 u32)
         8 + // created_at
         8 + // updated_at
         1 + // bump
+        1 + // vault_bump
         64; // padding for future fields
 
     pub fn validate_bet_amount(&self, amount: u64) -> Result<()> {
@@ -57,6 +108,103 @@ impl Casino {
         Ok(())
     }
 
+    pub fn validate_unpause_delay(&self) -> Result<()> {
+        require!(
+            self.unpause_delay >= 0 && self.unpause_delay <= super::MAX_UNPAUSE_DELAY,
+            crate::errors::CasinoError::InvalidConfiguration
+        );
+        Ok(())
+    }
+
+    /// New bets need an active, fully resumed casino outside maintenance with an
+    /// untripped circuit breaker
+    pub fn validate_accepting_bets(&self) -> Result<()> {
+        require!(self.is_active, crate::errors::CasinoError::CasinoNotActive);
+        require!(!self.is_paused && !self.resolve_only, crate::errors::CasinoError::CasinoPaused);
+        require!(
+            !self.is_in_maintenance(Clock::get()?.unix_timestamp),
+            crate::errors::CasinoError::MaintenanceModeActive
+        );
+        require!(!self.circuit_breaker.is_tripped(), crate::errors::CasinoError::CircuitBreakerTriggered);
+        Ok(())
+    }
+
+    pub fn is_in_maintenance(&self, now: i64) -> bool {
+        self.maintenance_window.map_or(false, |window| window.is_active(now))
+    }
+
+    pub fn pause(&mut self, authority: Pubkey, reason: PauseReason, now: i64) {
+        self.is_paused = true;
+        self.resolve_only = false;
+        self.pause_record.paused_by = authority;
+        self.pause_record.paused_at = now;
+        self.pause_record.unpause_at = now.saturating_add(self.unpause_delay);
+        self.pause_record.pause_reason = reason;
+        self.updated_at = now;
+    }
+
+    /// Leaves the pause once the unpause delay in force when pausing has passed, either
+    /// fully or into resolve-only mode; a resolve-only casino can be fully resumed at
+    /// any time
+    pub fn resume(&mut self, authority: Pubkey, reason: PauseReason, resolve_only: bool, now: i64) -> Result<()> {
+        require!(self.is_paused || self.resolve_only, crate::errors::CasinoError::InvalidStateTransition);
+        if self.is_paused {
+            require!(
+                now >= self.pause_record.unpause_at,
+                crate::errors::CasinoError::CooldownPeriodActive
+            );
+        }
+
+        self.is_paused = false;
+        self.resolve_only = resolve_only;
+        self.pause_record.resumed_by = authority;
+        self.pause_record.resumed_at = now;
+        self.pause_record.resume_reason = reason;
+        self.updated_at = now;
+        Ok(())
+    }
+
+    pub fn validate_kelly_fraction(&self) -> Result<()> {
+        require!(
+            self.kelly_fraction <= super::MAX_KELLY_FRACTION,
+            crate::errors::CasinoError::InvalidConfiguration
+        );
+        Ok(())
+    }
+
+    /// Largest bet whose maximum win stays within the risked share of free bankroll,
+    /// or None when risk mode is off
+    pub fn dynamic_max_bet(&self, max_multiplier: u64, vault_balance: u64) -> Option<u64> {
+        self.kelly_max_bet(self.free_vault_balance(vault_balance), max_multiplier)
+    }
+
+    /// Kelly-sized max bet against `free_balance` of any of the casino's vaults
+    pub fn kelly_max_bet(&self, free_balance: u64, max_multiplier: u64) -> Option<u64> {
+        if self.kelly_fraction == 0 || max_multiplier == 0 {
+            return None;
+        }
+        let at_risk = free_balance as u128 * self.kelly_fraction as u128;
+        Some((at_risk / max_multiplier as u128).min(u64::MAX as u128) as u64)
+    }
+
+    pub fn validate_bet_risk(&self, amount: u64, max_multiplier: u64, vault_balance: u64) -> Result<()> {
+        if let Some(max_bet) = self.dynamic_max_bet(max_multiplier, vault_balance) {
+            require_gte!(max_bet, amount, crate::errors::CasinoError::BetTooLarge);
+        }
+        Ok(())
+    }
+
+    pub fn validate_potential_payout(&self, potential_payout: u64, vault_balance: u64) -> Result<()> {
+        if self.max_payout > 0 {
+            require!(potential_payout <= self.max_payout, crate::errors::CasinoError::MaxPayoutExceeded);
+        }
+        require!(
+            potential_payout <= self.free_vault_balance(vault_balance),
+            crate::errors::CasinoError::InsufficientVaultFunds
+        );
+        Ok(())
+    }
+
     pub fn validate_house_edge(&self) -> Result<()> {
         require!(
             self.house_edge >= super::MIN_HOUSE_EDGE && self.house_edge <= super::MAX_HOUSE_EDGE,
@@ -73,6 +221,120 @@ impl Casino {
         Ok(())
     }
 
+    pub fn validate_cancellation_fee(&self) -> Result<()> {
+        require!(
+            (super::MIN_CANCELLATION_FEE..=super::MAX_CANCELLATION_FEE).contains(&self.cancellation_fee),
+            crate::errors::CasinoError::InvalidConfiguration
+        );
+        Ok(())
+    }
+
+    /// Proposes a new casino authority, replacing any pending proposal. Returns the
+    /// current authority.
+    pub fn propose_authority(&mut self, proposed: Pubkey, now: i64) -> Result<Pubkey> {
+        require_keys_neq!(proposed, Pubkey::default(), crate::errors::CasinoError::InvalidConfiguration);
+        require_keys_neq!(proposed, self.authority, crate::errors::CasinoError::InvalidConfiguration);
+        self.pending_authority = Some(proposed);
+        self.updated_at = now;
+        Ok(self.authority)
+    }
+
+    /// Hands the casino to `holder` if it was proposed to it. Returns the previous
+    /// authority.
+    pub fn accept_authority(&mut self, holder: Pubkey, now: i64) -> Result<Pubkey> {
+        require!(self.pending_authority == Some(holder), crate::errors::CasinoError::Unauthorized);
+        let previous = self.authority;
+        self.authority = holder;
+        self.pending_authority = None;
+        self.updated_at = now;
+        Ok(previous)
+    }
+
+    /// Announces a timelocked change; a queued change has to be cancelled before
+    /// another one can be announced
+    pub fn queue_config_change(&mut self, change: PendingConfigChange) -> Result<()> {
+        change.validate()?;
+        require!(!change.clears_override(), crate::errors::CasinoError::InvalidConfiguration);
+        require!(self.pending_config.is_none(), crate::errors::CasinoError::InvalidStateTransition);
+        self.pending_config = Some(change);
+        self.updated_at = change.queued_at;
+        Ok(())
+    }
+
+    /// Whether `change` would raise the house edge, a fee or the max bet
+    pub fn is_increase(&self, change: &PendingConfigChange) -> bool {
+        change.house_edge.flatten().map_or(false, |house_edge| house_edge > self.house_edge)
+            || change.treasury_fee.flatten().map_or(false, |treasury_fee| treasury_fee > self.treasury_fee)
+            || change.cancellation_fee.map_or(false, |cancellation_fee| cancellation_fee > self.cancellation_fee)
+            || change.max_bet.map_or(false, |max_bet| max_bet > self.max_bet)
+    }
+
+    /// Applies a passed governance proposal: cuts take effect immediately, while
+    /// house edge, fee and max bet increases are queued behind `CONFIG_TIMELOCK`.
+    /// Returns the queued change, if any.
+    pub fn update_parameters(&mut self, update: &ParameterUpdate, now: i64) -> Result<Option<PendingConfigChange>> {
+        update.validate()?;
+
+        let mut increase = PendingConfigChange {
+            house_edge: None,
+            treasury_fee: None,
+            cancellation_fee: None,
+            max_bet: None,
+            queued_at: now,
+            effective_at: now + CONFIG_TIMELOCK,
+            by_governance: true,
+        };
+        if let Some(house_edge) = update.house_edge {
+            if house_edge <= self.house_edge {
+                self.house_edge = house_edge;
+            } else {
+                increase.house_edge = Some(Some(house_edge));
+            }
+        }
+        if let Some(treasury_fee) = update.treasury_fee {
+            if treasury_fee <= self.treasury_fee {
+                self.treasury_fee = treasury_fee;
+            } else {
+                increase.treasury_fee = Some(Some(treasury_fee));
+            }
+        }
+        if let Some(max_bet) = update.max_bet {
+            require!(max_bet >= self.min_bet, crate::errors::CasinoError::InvalidConfiguration);
+            if max_bet <= self.max_bet {
+                self.max_bet = max_bet;
+            } else {
+                increase.max_bet = Some(max_bet);
+            }
+        }
+        self.updated_at = now;
+
+        if increase.house_edge.is_none() && increase.treasury_fee.is_none() && increase.max_bet.is_none() {
+            return Ok(None);
+        }
+        self.queue_config_change(increase)?;
+        Ok(Some(increase))
+    }
+
+    /// Applies a queued change once its timelock has passed
+    pub fn apply_config_change(&mut self, change: &PendingConfigChange) -> Result<()> {
+        if let Some(Some(house_edge)) = change.house_edge {
+            self.house_edge = house_edge;
+        }
+        if let Some(Some(treasury_fee)) = change.treasury_fee {
+            self.treasury_fee = treasury_fee;
+        }
+        if let Some(cancellation_fee) = change.cancellation_fee {
+            self.cancellation_fee = cancellation_fee;
+        }
+        if let Some(max_bet) = change.max_bet {
+            self.max_bet = max_bet;
+        }
+        require!(self.max_bet >= self.min_bet, crate::errors::CasinoError::InvalidConfiguration);
+        self.validate_house_edge()?;
+        self.validate_treasury_fee()?;
+        self.validate_cancellation_fee()
+    }
+
     pub fn calculate_house_edge(&self, bet_amount: u64) -> u64 {
         (bet_amount * self.house_edge as u64) / BASIS_POINTS
     }
@@ -81,6 +343,13 @@ impl Casino {
         (bet_amount * self.treasury_fee as u64) / BASIS_POINTS
     }
 
+    /// Cancellation fee on `bet_amount`, rounded up so a cancelled bet never costs nothing
+    pub fn calculate_cancellation_fee(&self, bet_amount: u64) -> Result<u64> {
+        let fee_bps = self.cancellation_fee.max(super::MIN_CANCELLATION_FEE) as u128;
+        let fee = (bet_amount as u128 * fee_bps + BASIS_POINTS as u128 - 1) / BASIS_POINTS as u128;
+        u64::try_from(fee).map_err(|_| error!(crate::errors::CasinoError::ArithmeticOverflow))
+    }
+
     pub fn calculate_max_payout(&self, bet_amount: u64, multiplier: u64) -> u64 {
         let gross_payout = (bet_amount * multiplier) / BASIS_POINTS;
         let house_edge = self.calculate_house_edge(bet_amount);
@@ -89,6 +358,37 @@ impl Casino {
         gross_payout.saturating_sub(house_edge).saturating_sub(treasury_fee)
     }
 
+    pub fn free_vault_balance(&self, vault_balance: u64) -> u64 {
+        vault_balance
+            .saturating_sub(self.reserved_liability)
+            .saturating_sub(self.treasury_owed)
+    }
+
+    pub fn accrue_treasury_fee(&mut self, amount: u64) {
+        self.treasury_owed = self.treasury_owed.saturating_add(amount);
+    }
+
+    pub fn reserve_liability(&mut self, amount: u64) -> Result<()> {
+        self.reserved_liability = self.reserved_liability
+            .checked_add(amount)
+            .ok_or(crate::errors::CasinoError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    pub fn release_liability(&mut self, amount: u64) {
+        self.reserved_liability = self.reserved_liability.saturating_sub(amount);
+    }
+
+    /// Native SOL casinos take bets in lamports instead of SPL tokens
+    pub fn is_native(&self) -> bool {
+        self.vault_ref().is_native()
+    }
+
+    /// Vault for bets in the casino's primary token
+    pub fn vault_ref(&self) -> VaultRef {
+        VaultRef { mint: self.token_mint, vault: self.vault }
+    }
+
     pub fn is_operational(&self) -> bool {
         self.is_active && !self.is_paused
     }
@@ -104,6 +404,61 @@ impl Casino {
         let profit = house_edge_taken as i64 - payout as i64;
         self.stats.total_profit = self.stats.total_profit.saturating_add(profit);
         
+        let now = Clock::get().unwrap().unix_timestamp;
+        self.circuit_breaker.record_result(bet_amount, payout, now);
+        self.updated_at = now;
+    }
+
+    /// Roulette settles a round in one go, but each bet in it counts as a game
+    pub fn update_round_stats(&mut self, bet_count: u64, total_staked: u64, payout: u64) {
+        if bet_count == 0 {
+            return;
+        }
+        self.update_stats(total_staked, payout, 0, 0);
+        self.stats.total_games = self.stats.total_games.saturating_add(bet_count - 1);
+    }
+
+    /// Record a game refunded after the operator failed to resolve it in time
+    pub fn record_expired_game(&mut self, potential_payout: u64) {
+        self.stats.games_expired = self.stats.games_expired.saturating_add(1);
+        self.release_liability(potential_payout);
+        self.updated_at = Clock::get().unwrap().unix_timestamp;
+    }
+
+    /// Record a game cancelled by the player, keeping the cancellation fee as profit
+    pub fn record_cancelled_game(&mut self, potential_payout: u64, cancellation_fee: u64) {
+        self.stats.total_profit = self.stats.total_profit.saturating_add(cancellation_fee as i64);
+        self.release_liability(potential_payout);
+        self.updated_at = Clock::get().unwrap().unix_timestamp;
+    }
+
+    /// Jackpot slices are tracked apart from house edge and treasury fees
+    pub fn record_jackpot_contribution(&mut self, contribution: u64) -> Result<()> {
+        self.stats.jackpot_contributions = self.stats.jackpot_contributions.saturating_add(contribution);
+        self.reserve_liability(contribution)
+    }
+
+    /// Only bets priced against the casino's price source count towards USD volume
+    pub fn record_usd_volume(&mut self, usd_value: Option<u64>) {
+        if let Some(usd_value) = usd_value {
+            self.stats.volume_usd = self.stats.volume_usd.saturating_add(usd_value);
+        }
+    }
+
+    pub fn record_jackpot_payout(&mut self, payout: u64, payout_usd: Option<u64>) {
+        self.stats.jackpot_payouts = self.stats.jackpot_payouts.saturating_add(payout);
+        if let Some(payout_usd) = payout_usd {
+            self.stats.jackpot_payouts_usd = self.stats.jackpot_payouts_usd.saturating_add(payout_usd);
+        }
+        self.release_liability(payout);
+        self.updated_at = Clock::get().unwrap().unix_timestamp;
+    }
+
+    /// Record a settled player-vs-player duel, where the house only takes the treasury fee
+    pub fn record_duel(&mut self, pot: u64, treasury_fee_taken: u64) {
+        self.stats.total_volume = self.stats.total_volume.saturating_add(pot);
+        self.stats.treasury_fees_collected = self.stats.treasury_fees_collected.saturating_add(treasury_fee_taken);
+        
         self.updated_at = Clock::get().unwrap().unix_timestamp;
     }
 
@@ -0,0 +1,201 @@
+use anchor_lang::prelude::*;
+use super::{Casino, CasinoStats, CircuitBreaker, PendingConfigChange, VaultRef};
+
+/// Additional token accepted by a casino, with its own vault, limits, stats,
+/// treasury fees and circuit breaker. The casino's Kelly fraction sizes bets
+/// against this vault's free balance.
+#[account]
+pub struct CasinoMint {
+    /// Casino accepting this mint
+    pub casino: Pubkey,
+    /// Accepted token mint
+    pub mint: Pubkey,
+    /// Vault token account for this mint, owned by the casino PDA
+    pub vault: Pubkey,
+    /// Minimum bet amount in token units
+    pub min_bet: u64,
+    /// Maximum bet amount in token units
+    pub max_bet: u64,
+    /// Maximum payout of a single game in token units (0 = bounded by vault only)
+    pub max_payout: u64,
+    /// House edge override in basis points (None = casino default)
+    pub house_edge: Option<u16>,
+    /// Treasury fee override in basis points (None = casino default)
+    pub treasury_fee: Option<u16>,
+    /// Queued increase or removal of the overrides, visible until it takes effect
+    pub pending_config: Option<PendingConfigChange>,
+    /// Whether bets in this mint are accepted
+    pub is_active: bool,
+    /// Potential payouts currently reserved against this vault
+    pub reserved_liability: u64,
+    /// Treasury fees accrued in this vault but not yet withdrawn
+    pub treasury_owed: u64,
+    /// Drawdown circuit breaker for bets in this mint, in its token units
+    pub circuit_breaker: CircuitBreaker,
+    /// Statistics for bets in this mint
+    pub stats: CasinoStats,
+    /// Timestamp when mint was added
+    pub created_at: i64,
+    /// Last update timestamp
+    pub updated_at: i64,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl CasinoMint {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // casino
+        32 + // mint
+        32 + // vault
+        8 + // min_bet
+        8 + // max_bet
+        8 + // max_payout
+        1 + 2 + // house_edge (optional)
+        1 + 2 + // treasury_fee (optional)
+        1 + PendingConfigChange::LEN + // pending_config (optional)
+        1 + // is_active
+        8 + // reserved_liability
+        8 + // treasury_owed
+        CircuitBreaker::LEN + // circuit_breaker
+        (11 * 8 + 4) + // stats (11 u64s + 1 u32)
+        8 + // created_at
+        8 + // updated_at
+        1 + // bump
+        64; // padding for future fields
+
+    pub fn vault_ref(&self) -> VaultRef {
+        VaultRef { mint: self.mint, vault: self.vault }
+    }
+
+    pub fn validate_config(&self) -> Result<()> {
+        require!(self.min_bet > 0 && self.min_bet <= self.max_bet, crate::errors::CasinoError::InvalidConfiguration);
+        if let Some(house_edge) = self.house_edge {
+            require!(
+                (super::MIN_HOUSE_EDGE..=super::MAX_HOUSE_EDGE).contains(&house_edge),
+                crate::errors::CasinoError::InvalidHouseEdge
+            );
+        }
+        if let Some(treasury_fee) = self.treasury_fee {
+            require!(treasury_fee <= super::MAX_TREASURY_FEE, crate::errors::CasinoError::InvalidTreasuryFee);
+        }
+        Ok(())
+    }
+
+    /// Announces a timelocked change to the overrides; a queued change has to be
+    /// cancelled before another one can be announced
+    pub fn queue_config_change(&mut self, change: PendingConfigChange) -> Result<()> {
+        change.validate()?;
+        require!(change.cancellation_fee.is_none(), crate::errors::CasinoError::InvalidConfiguration);
+        require!(self.pending_config.is_none(), crate::errors::CasinoError::InvalidStateTransition);
+        self.pending_config = Some(change);
+        self.updated_at = change.queued_at;
+        Ok(())
+    }
+
+    /// Whether `change` would raise the effective house edge or treasury fee, clearing
+    /// an override included, or the max bet
+    pub fn is_increase(&self, casino: &Casino, change: &PendingConfigChange) -> bool {
+        change.house_edge.map_or(false, |house_edge| {
+            house_edge.unwrap_or(casino.house_edge) > self.effective_house_edge(casino)
+        }) || change.treasury_fee.map_or(false, |treasury_fee| {
+            treasury_fee.unwrap_or(casino.treasury_fee) > self.effective_treasury_fee(casino)
+        }) || change.max_bet.map_or(false, |max_bet| max_bet > self.max_bet)
+    }
+
+    /// Applies a queued change to the overrides once its timelock has passed
+    pub fn apply_config_change(&mut self, change: &PendingConfigChange) -> Result<()> {
+        if let Some(house_edge) = change.house_edge {
+            self.house_edge = house_edge;
+        }
+        if let Some(treasury_fee) = change.treasury_fee {
+            self.treasury_fee = treasury_fee;
+        }
+        if let Some(max_bet) = change.max_bet {
+            self.max_bet = max_bet;
+        }
+        self.validate_config()
+    }
+
+    pub fn effective_house_edge(&self, casino: &Casino) -> u16 {
+        self.house_edge.unwrap_or(casino.house_edge)
+    }
+
+    pub fn effective_treasury_fee(&self, casino: &Casino) -> u16 {
+        self.treasury_fee.unwrap_or(casino.treasury_fee)
+    }
+
+    pub fn validate_bet_amount(&self, amount: u64) -> Result<()> {
+        require!(self.is_active, crate::errors::CasinoError::InvalidTokenMint);
+        require!(!self.circuit_breaker.is_tripped(), crate::errors::CasinoError::CircuitBreakerTriggered);
+        require!(amount >= self.min_bet, crate::errors::CasinoError::BetTooSmall);
+        require!(amount <= self.max_bet, crate::errors::CasinoError::BetTooLarge);
+        Ok(())
+    }
+
+    pub fn validate_potential_payout(&self, potential_payout: u64, vault_balance: u64) -> Result<()> {
+        if self.max_payout > 0 {
+            require!(potential_payout <= self.max_payout, crate::errors::CasinoError::MaxPayoutExceeded);
+        }
+        require!(
+            potential_payout <= self.free_vault_balance(vault_balance),
+            crate::errors::CasinoError::InsufficientVaultFunds
+        );
+        Ok(())
+    }
+
+    /// Bounds the bet by the casino's Kelly fraction of this vault's free balance
+    pub fn validate_bet_risk(&self, casino: &Casino, amount: u64, max_multiplier: u64, vault_balance: u64) -> Result<()> {
+        if let Some(max_bet) = casino.kelly_max_bet(self.free_vault_balance(vault_balance), max_multiplier) {
+            require_gte!(max_bet, amount, crate::errors::CasinoError::BetTooLarge);
+        }
+        Ok(())
+    }
+
+    pub fn free_vault_balance(&self, vault_balance: u64) -> u64 {
+        vault_balance
+            .saturating_sub(self.reserved_liability)
+            .saturating_sub(self.treasury_owed)
+    }
+
+    pub fn accrue_treasury_fee(&mut self, amount: u64) {
+        self.treasury_owed = self.treasury_owed.saturating_add(amount);
+    }
+
+    pub fn reserve_liability(&mut self, amount: u64) -> Result<()> {
+        self.reserved_liability = self.reserved_liability
+            .checked_add(amount)
+            .ok_or(crate::errors::CasinoError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    pub fn release_liability(&mut self, amount: u64) {
+        self.reserved_liability = self.reserved_liability.saturating_sub(amount);
+    }
+
+    pub fn update_stats(&mut self, bet_amount: u64, payout: u64, house_edge_taken: u64, treasury_fee_taken: u64) {
+        self.stats.total_games = self.stats.total_games.saturating_add(1);
+        self.stats.total_volume = self.stats.total_volume.saturating_add(bet_amount);
+        self.stats.total_payouts = self.stats.total_payouts.saturating_add(payout);
+        self.stats.house_edge_collected = self.stats.house_edge_collected.saturating_add(house_edge_taken);
+        self.stats.treasury_fees_collected = self.stats.treasury_fees_collected.saturating_add(treasury_fee_taken);
+
+        let profit = house_edge_taken as i64 - payout as i64;
+        self.stats.total_profit = self.stats.total_profit.saturating_add(profit);
+
+        let now = Clock::get().unwrap().unix_timestamp;
+        self.circuit_breaker.record_result(bet_amount, payout, now);
+        self.updated_at = now;
+    }
+
+    pub fn record_expired_game(&mut self, potential_payout: u64) {
+        self.stats.games_expired = self.stats.games_expired.saturating_add(1);
+        self.release_liability(potential_payout);
+        self.updated_at = Clock::get().unwrap().unix_timestamp;
+    }
+
+    pub fn record_cancelled_game(&mut self, potential_payout: u64, cancellation_fee: u64) {
+        self.stats.total_profit = self.stats.total_profit.saturating_add(cancellation_fee as i64);
+        self.release_liability(potential_payout);
+        self.updated_at = Clock::get().unwrap().unix_timestamp;
+    }
+}
@@ -0,0 +1,157 @@
+use anchor_lang::prelude::*;
+use super::{DuelStatus, BASIS_POINTS, DUEL_REVEAL_TIMEOUT, DUEL_JOIN_TIMEOUT};
+
+#[account]
+pub struct Duel {
+    /// Casino hosting the duel
+    pub casino: Pubkey,
+    /// Player who opened the duel
+    pub creator: Pubkey,
+    /// Player who matched the duel
+    pub opponent: Option<Pubkey>,
+    /// Creator-chosen identifier, part of the PDA seeds
+    pub duel_id: u64,
+    /// Stake put up by each player in token units
+    pub stake: u64,
+    /// Coin side picked by the creator (0 = heads, 1 = tails)
+    pub creator_side: u8,
+    /// SHA-256 hash of the creator's client seed
+    pub creator_seed_hash: String,
+    /// SHA-256 hash of the opponent's client seed
+    pub opponent_seed_hash: Option<String>,
+    /// Creator's client seed (revealed after matching)
+    pub creator_seed: Option<String>,
+    /// Opponent's client seed (revealed after matching)
+    pub opponent_seed: Option<String>,
+    /// Current duel status
+    pub status: DuelStatus,
+    /// Winner of the pot (None if refunded)
+    pub winner: Option<Pubkey>,
+    /// Amount paid to the winner
+    pub payout: u64,
+    /// Treasury fee taken from the pot
+    pub treasury_fee_taken: u64,
+    /// USD value of one stake when the duel was created, if the casino prices bets
+    pub stake_usd: Option<u64>,
+    /// Timestamp when duel was created
+    pub created_at: i64,
+    /// Timestamp when duel was matched
+    pub matched_at: Option<i64>,
+    /// Timestamp when duel was settled or cancelled
+    pub settled_at: Option<i64>,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl Duel {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // casino
+        32 + // creator
+        1 + 32 + // opponent (optional)
+        8 + // duel_id
+        8 + // stake
+        1 + // creator_side
+        4 + 64 + // creator_seed_hash
+        1 + 4 + 64 + // opponent_seed_hash (optional)
+        1 + 4 + 64 + // creator_seed (optional)
+        1 + 4 + 64 + // opponent_seed (optional)
+        1 + // status
+        1 + 32 + // winner (optional)
+        8 + // payout
+        8 + // treasury_fee_taken
+        1 + 8 + // stake_usd (optional)
+        8 + // created_at
+        1 + 8 + // matched_at (optional)
+        1 + 8 + // settled_at (optional)
+        1 + // bump
+        64; // padding for future fields
+
+    pub fn pot(&self) -> Result<u64> {
+        self.stake
+            .checked_mul(2)
+            .ok_or(crate::errors::CasinoError::ArithmeticOverflow.into())
+    }
+
+    pub fn can_be_cancelled(&self, now: i64) -> bool {
+        matches!(self.status, DuelStatus::Open) && now >= self.created_at + DUEL_JOIN_TIMEOUT
+    }
+
+    pub fn reveal_deadline(&self) -> Option<i64> {
+        self.matched_at.map(|matched| matched + DUEL_REVEAL_TIMEOUT)
+    }
+
+    pub fn reveal_seed(&mut self, player: &Pubkey, client_seed: String, now: i64) -> Result<()> {
+        require!(matches!(self.status, DuelStatus::Matched), crate::errors::CasinoError::InvalidStateTransition);
+        require!(
+            self.reveal_deadline().map_or(false, |deadline| now <= deadline),
+            crate::errors::CasinoError::GameExpired
+        );
+
+        if *player == self.creator {
+            require!(self.creator_seed.is_none(), crate::errors::CasinoError::SeedAlreadyUsed);
+            require!(
+                hash_seed(&client_seed) == self.creator_seed_hash,
+                crate::errors::CasinoError::InvalidClientSeed
+            );
+            self.creator_seed = Some(client_seed);
+        } else if Some(*player) == self.opponent {
+            require!(self.opponent_seed.is_none(), crate::errors::CasinoError::SeedAlreadyUsed);
+            require!(
+                self.opponent_seed_hash.as_deref() == Some(hash_seed(&client_seed).as_str()),
+                crate::errors::CasinoError::InvalidClientSeed
+            );
+            self.opponent_seed = Some(client_seed);
+        } else {
+            return Err(crate::errors::CasinoError::Unauthorized.into());
+        }
+
+        Ok(())
+    }
+
+    /// Decides the winner of a matched duel.
+    ///
+    /// Once both seeds are revealed the coin is flipped. After the reveal deadline a
+    /// player who revealed wins by forfeit, and if neither revealed both are refunded.
+    pub fn determine_winner(&self, now: i64) -> Result<Option<Pubkey>> {
+        require!(matches!(self.status, DuelStatus::Matched), crate::errors::CasinoError::InvalidStateTransition);
+        let opponent = self.opponent.ok_or(crate::errors::CasinoError::InvalidStateTransition)?;
+        let deadline_passed = self.reveal_deadline().map_or(false, |deadline| now > deadline);
+
+        match (&self.creator_seed, &self.opponent_seed) {
+            (Some(creator_seed), Some(opponent_seed)) => {
+                let side = self.flip(creator_seed, opponent_seed);
+                Ok(Some(if side == self.creator_side { self.creator } else { opponent }))
+            },
+            (Some(_), None) if deadline_passed => Ok(Some(self.creator)),
+            (None, Some(_)) if deadline_passed => Ok(Some(opponent)),
+            (None, None) if deadline_passed => Ok(None),
+            _ => Err(crate::errors::CasinoError::DuelNotSettleable.into()),
+        }
+    }
+
+    /// Treasury cut of the escrowed pot, which is below `pot()` when the mint
+    /// charges transfer fees
+    pub fn calculate_treasury_fee(&self, escrowed: u64, treasury_fee: u16) -> u64 {
+        ((escrowed as u128 * treasury_fee as u128) / BASIS_POINTS as u128) as u64
+    }
+
+    fn flip(&self, creator_seed: &str, opponent_seed: &str) -> u8 {
+        use sha2::{Sha256, Digest};
+
+        let combined_seed = format!("{}-{}-{}", creator_seed, opponent_seed, self.duel_id);
+
+        let mut hasher = Sha256::new();
+        hasher.update(combined_seed.as_bytes());
+        let hash = hasher.finalize();
+
+        hash[0] % 2
+    }
+}
+
+pub fn hash_seed(seed: &str) -> String {
+    use sha2::{Sha256, Digest};
+
+    let mut hasher = Sha256::new();
+    hasher.update(seed.as_bytes());
+    hex::encode(hasher.finalize())
+}
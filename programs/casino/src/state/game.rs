@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use super::{GameType, GameStatus, GameResult, ProvableFairData, PlayerStats};
+use super::{Casino, CasinoMint, GameType, GameStatus, GameResult, ProvableFairData, SideBet, VaultRef};
 
 #[account]
 pub struct Game {
@@ -7,12 +7,16 @@ pub struct Game {
     pub player: Pubkey,
     /// Casino this game belongs to
     pub casino: Pubkey,
+    /// Token mint the bet was placed in
+    pub mint: Pubkey,
     /// Type of game being played
     pub game_type: GameType,
     /// Bet amount in token units
     pub bet_amount: u64,
     /// Player's prediction/choice
     pub prediction: Vec<u8>,
+    /// Optional side bets placed alongside the main bet
+    pub side_bets: Vec<SideBet>,
     /// Game result (populated after resolution)
     pub result: Option<GameResult>,
     /// Provably fair data
@@ -29,6 +33,16 @@ pub struct Game {
     pub expires_at: i64,
     /// Game session ID for tracking
     pub session_id: u64,
+    /// Slice of the wager added to the jackpot; games that contributed nothing cannot win it
+    pub jackpot_contribution: u64,
+    /// Jackpot locked for the player when resolution triggered it
+    pub jackpot_payout: u64,
+    /// Whether the jackpot has been paid for this game
+    pub jackpot_claimed: bool,
+    /// House edge in basis points when the game was created, used to settle it
+    pub house_edge: u16,
+    /// Treasury fee in basis points when the game was created, used to settle it
+    pub treasury_fee: u16,
     /// Bump seed for PDA
     pub bump: u8,
 }
@@ -37,10 +51,12 @@ impl Game {
     pub const LEN: usize = 8 + // discriminator
         32 + // player
         32 + // casino
+        32 + // mint
         1 + // game_type
         8 + // bet_amount
         4 + 256 + // prediction (vec with max 256 bytes)
-        1 + (4 + 256 + 8 + 8 + 8) + // result (optional)
+        4 + super::MAX_SIDE_BETS * (1 + 1 + 8) + // side_bets
+        1 + (4 + 256 + 8 + 8 + 8 + 4 + super::MAX_SIDE_BETS * 8) + // result (optional)
         (4 + 64 + 4 + 64 + 8 + 1 + 4 + 64) + // provable_fair
         1 + // status
         8 + // created_at
@@ -48,6 +64,11 @@ impl Game {
         1 + 8 + // claimed_at (optional)
         8 + // expires_at
         8 + // session_id
+        8 + // jackpot_contribution
+        8 + // jackpot_payout
+        1 + // jackpot_claimed
+        2 + // house_edge
+        2 + // treasury_fee
         1 + // bump
         128; // padding for future fields
 
@@ -55,8 +76,13 @@ impl Game {
         Clock::get().unwrap().unix_timestamp > self.expires_at
     }
 
+    /// The seed can only be revealed once the cancellation window has closed, so the
+    /// player never gets to cancel a game whose outcome is already public
     pub fn can_be_resolved(&self) -> bool {
-        matches!(self.status, GameStatus::Active) && !self.is_expired()
+        let now = Clock::get().unwrap().unix_timestamp;
+        matches!(self.status, GameStatus::Active) &&
+        now > self.created_at + super::GAME_CANCEL_WINDOW &&
+        !self.is_expired()
     }
 
     pub fn can_claim_winnings(&self) -> bool {
@@ -65,6 +91,28 @@ impl Game {
         self.claimed_at.is_none()
     }
 
+    /// A game is settled once no more funds can move for it
+    pub fn is_settled(&self) -> bool {
+        match self.status {
+            GameStatus::Claimed | GameStatus::Cancelled | GameStatus::Expired => true,
+            GameStatus::Resolved => self.result.as_ref().map_or(false, |r| r.payout == 0),
+            _ => false,
+        }
+    }
+
+    pub fn settled_at(&self) -> i64 {
+        self.claimed_at.or(self.resolved_at).unwrap_or(self.expires_at)
+    }
+
+    /// The player may close a settled game at any time; anyone else must wait out the
+    /// grace period so the player can still claim a jackpot. A locked jackpot keeps
+    /// the game open until it is claimed.
+    pub fn can_be_closed_by(&self, closer: &Pubkey, now: i64) -> bool {
+        self.is_settled() &&
+        (self.jackpot_payout == 0 || self.jackpot_claimed) &&
+        (*closer == self.player || now >= self.settled_at() + super::GAME_CLOSE_GRACE_PERIOD)
+    }
+
     pub fn resolve_game(&mut self, result: GameResult) -> Result<()> {
         require!(self.can_be_resolved(), crate::errors::CasinoError::CannotResolveGame);
         
@@ -85,11 +133,18 @@ impl Game {
         Ok(payout)
     }
 
+    /// Games can only be cancelled shortly after creation, while the committed server
+    /// seed cannot yet be revealed and before a multi-step game has moved past its
+    /// opening state
+    pub fn can_be_cancelled(&self) -> bool {
+        let now = Clock::get().unwrap().unix_timestamp;
+        matches!(self.status, GameStatus::Created | GameStatus::Active) &&
+        self.provable_fair.server_seed.is_none() &&
+        now <= self.created_at + super::GAME_CANCEL_WINDOW
+    }
+
     pub fn cancel_game(&mut self) -> Result<()> {
-        require!(
-            matches!(self.status, GameStatus::Created | GameStatus::Active),
-            crate::errors::CasinoError::CannotCancelGame
-        );
+        require!(self.can_be_cancelled(), crate::errors::CasinoError::CannotCancelGame);
         
         self.status = GameStatus::Cancelled;
         Ok(())
@@ -98,7 +153,7 @@ impl Game {
     pub fn expire_game(&mut self) -> Result<()> {
         require!(self.is_expired(), crate::errors::CasinoError::GameNotExpired);
         require!(
-            !matches!(self.status, GameStatus::Resolved | GameStatus::Claimed | GameStatus::Cancelled),
+            matches!(self.status, GameStatus::Created | GameStatus::Active | GameStatus::Resolving),
             crate::errors::CasinoError::CannotExpireGame
         );
         
@@ -106,6 +161,20 @@ impl Game {
         Ok(())
     }
 
+    /// Vault the stake was paid into, based on the mint recorded at creation
+    pub fn vault_ref(&self, casino: &Casino, casino_mint: Option<&CasinoMint>) -> Result<VaultRef> {
+        match casino_mint {
+            Some(casino_mint) => {
+                require_keys_eq!(casino_mint.mint, self.mint, crate::errors::CasinoError::InvalidTokenMint);
+                Ok(casino_mint.vault_ref())
+            },
+            None => {
+                require_keys_eq!(casino.token_mint, self.mint, crate::errors::CasinoError::InvalidTokenMint);
+                Ok(casino.vault_ref())
+            },
+        }
+    }
+
     pub fn get_game_duration(&self) -> i64 {
         match self.resolved_at {
             Some(resolved) => resolved - self.created_at,
@@ -160,6 +229,14 @@ impl Game {
                 let result = hash[0] % 37; // 0-36 for European roulette
                 Ok(vec![result])
             },
+            GameType::Limbo => {
+                // Raw 64-bit draw, converted to a crash multiplier in calculate_payout
+                Ok(hash[0..8].to_vec())
+            },
+            GameType::Baccarat => {
+                // Whole hash, the coup is dealt from it in calculate_payout and by pair side bets
+                Ok(hash.to_vec())
+            },
             _ => {
                 // For more complex games, implement specific logic
                 Ok(hash[0..4].to_vec())
@@ -167,9 +244,124 @@ impl Game {
         }
     }
 
+    /// Independent roll for the progressive jackpot, derived from the revealed seeds
+    pub fn jackpot_roll(&self, server_seed: &str) -> u32 {
+        use sha2::{Sha256, Digest};
+        
+        let combined_seed = format!(
+            "{}-{}-{}-jackpot",
+            server_seed,
+            self.provable_fair.client_seed,
+            self.provable_fair.nonce
+        );
+        
+        let mut hasher = Sha256::new();
+        hasher.update(combined_seed.as_bytes());
+        let hash = hasher.finalize();
+        
+        u32::from_le_bytes([hash[0], hash[1], hash[2], hash[3]])
+    }
+
+    /// Target multiplier in basis points, encoded as a little-endian u64 prediction
+    pub fn limbo_target(&self) -> Result<u64> {
+        require!(self.prediction.len() == 8, crate::errors::CasinoError::InvalidPrediction);
+        
+        let mut bytes = [0u8; 8];
+        bytes.copy_from_slice(&self.prediction[0..8]);
+        let target = u64::from_le_bytes(bytes);
+        
+        require!(
+            (super::LIMBO_MIN_TARGET..=super::LIMBO_MAX_TARGET).contains(&target),
+            crate::errors::CasinoError::InvalidPrediction
+        );
+        Ok(target)
+    }
+
+    /// Highest multiplier this game can pay out, used for vault liability checks
+    pub fn max_multiplier(&self) -> Result<u64> {
+        let multiplier = match self.game_type {
+            GameType::CoinFlip => super::COINFLIP_PAYOUT,
+            GameType::DiceRoll => super::DICE_MAX_PAYOUT,
+            GameType::Slots => super::SLOTS_MAX_PAYOUT,
+            GameType::Blackjack => super::BLACKJACK_PAYOUT,
+            GameType::Roulette => super::ROULETTE_STRAIGHT_PAYOUT,
+            GameType::Limbo => self.limbo_target()?,
+            GameType::Baccarat => super::BACCARAT_TIE_PAYOUT,
+            _ => 0,
+        };
+        Ok(multiplier)
+    }
+
+    /// Checks the prediction of the single-shot games `calculate_payout` can settle
+    pub fn validate_prediction(&self) -> Result<()> {
+        let valid = match self.game_type {
+            GameType::CoinFlip => self.prediction.len() == 1 && self.prediction[0] < 2,
+            GameType::DiceRoll => {
+                // [target, over_under]
+                self.prediction.len() == 2 &&
+                (1..=99).contains(&self.prediction[0]) &&
+                self.prediction[1] < 2
+            },
+            GameType::Slots => self.prediction.is_empty(),
+            GameType::Roulette => {
+                self.prediction.len() == 1 && (self.prediction[0] as usize) < super::ROULETTE_POCKETS
+            },
+            GameType::Limbo => {
+                self.limbo_target()?;
+                true
+            },
+            GameType::Baccarat => {
+                // 0 = player, 1 = banker, 2 = tie
+                self.prediction.len() == 1 && self.prediction[0] <= super::BACCARAT_TIE
+            },
+            _ => return err!(crate::errors::CasinoError::InvalidGameType),
+        };
+        require!(valid, crate::errors::CasinoError::InvalidPrediction);
+        Ok(())
+    }
+
+    pub fn validate_side_bets(&self) -> Result<()> {
+        require!(self.side_bets.len() <= super::MAX_SIDE_BETS, crate::errors::CasinoError::InvalidSideBet);
+        
+        for side_bet in &self.side_bets {
+            require!(
+                side_bet.kind.is_allowed_for(self.game_type),
+                crate::errors::CasinoError::InvalidSideBet
+            );
+            require!(
+                side_bet.amount > 0 && side_bet.amount <= self.bet_amount,
+                crate::errors::CasinoError::InvalidSideBet
+            );
+            side_bet.kind.validate_selection(side_bet.selection)?;
+        }
+        Ok(())
+    }
+
+    /// Main bet plus all side bet stakes
+    pub fn total_wager(&self) -> Result<u64> {
+        self.side_bets.iter().try_fold(self.bet_amount, |total, side_bet| {
+            total
+                .checked_add(side_bet.amount)
+                .ok_or(crate::errors::CasinoError::ArithmeticOverflow.into())
+        })
+    }
+
+    /// Worst-case payout of the main bet and every side bet, reserved against the vault
+    pub fn potential_payout(&self) -> Result<u64> {
+        let payout = (self.bet_amount as u128 * self.max_multiplier()? as u128) / super::BASIS_POINTS as u128;
+        let main_payout = u64::try_from(payout).map_err(|_| crate::errors::CasinoError::ArithmeticOverflow)?;
+        
+        self.side_bets.iter().try_fold(main_payout, |total, side_bet| {
+            total
+                .checked_add(side_bet.potential_payout()?)
+                .ok_or(crate::errors::CasinoError::ArithmeticOverflow.into())
+        })
+    }
+
     pub fn calculate_payout(&self, outcome: &[u8], casino_house_edge: u16) -> Result<GameResult> {
         let base_amount = self.bet_amount;
         let house_edge_amount = (base_amount * casino_house_edge as u64) / super::BASIS_POINTS;
+        let mut result_outcome = outcome.to_vec();
         
         let (won, multiplier) = match self.game_type {
             GameType::CoinFlip => {
@@ -204,11 +396,8 @@ impl Game {
                         100 - target as u64
                     };
                     
-                    if probability > 0 {
-                        (9800 * 100) / probability // 98% RTP
-                    } else {
-                        0
-                    }
+                    // 98% RTP
+                    (9800 * 100u64).checked_div(probability).unwrap_or(0)
                 } else {
                     0
                 };
@@ -243,6 +432,22 @@ impl Game {
                 let multiplier = if won { super::ROULETTE_STRAIGHT_PAYOUT } else { 0 };
                 (won, multiplier)
             },
+            GameType::Limbo => {
+                let target = self.limbo_target()?;
+                let rolled = limbo_multiplier(outcome, casino_house_edge)?;
+                
+                // Store the full-precision rolled multiplier as the outcome
+                result_outcome = rolled.to_le_bytes().to_vec();
+                
+                let won = rolled >= target;
+                let multiplier = if won { target } else { 0 };
+                (won, multiplier)
+            },
+            GameType::Baccarat => {
+                let coup = super::Coup::deal(outcome)?;
+                let multiplier = coup.multiplier(self.prediction[0]);
+                (multiplier > 0, multiplier)
+            },
             _ => {
                 // Default case for other games
                 (false, 0)
@@ -250,19 +455,149 @@ impl Game {
         };
 
         let gross_payout = if won {
-            (base_amount * multiplier) / super::BASIS_POINTS
+            let payout = (base_amount as u128 * multiplier as u128) / super::BASIS_POINTS as u128;
+            u64::try_from(payout).map_err(|_| crate::errors::CasinoError::ArithmeticOverflow)?
         } else {
             0
         };
 
-        let net_payout = gross_payout.saturating_sub(house_edge_amount);
+        // Limbo prices the house edge into the win probability and the baccarat paytable
+        // carries its own, so nothing is deducted
+        let house_edge_taken = if matches!(self.game_type, GameType::Limbo | GameType::Baccarat) {
+            0
+        } else {
+            house_edge_amount
+        };
+        let net_payout = gross_payout.saturating_sub(house_edge_taken);
+        
+        // Side bet paytables already carry their edge
+        let side_bet_payouts = self.side_bets
+            .iter()
+            .map(|side_bet| side_bet.payout(&result_outcome))
+            .collect::<Result<Vec<u64>>>()?;
+        let payout = side_bet_payouts.iter().try_fold(net_payout, |total, side_payout| {
+            total.checked_add(*side_payout).ok_or(crate::errors::CasinoError::ArithmeticOverflow)
+        })?;
         
         Ok(GameResult {
-            outcome: outcome.to_vec(),
+            outcome: result_outcome,
             multiplier,
-            payout: net_payout,
-            house_edge_taken: house_edge_amount,
+            payout,
+            house_edge_taken,
             treasury_fee_taken: 0, // Calculated separately
+            side_bet_payouts,
         })
     }
 }
+
+/// Converts a raw 64-bit draw into a crash-style multiplier in basis points.
+///
+/// The multiplier is `(1 - house_edge) / u` for `u` uniform in `(0, 1]`, so the
+/// probability of reaching a target `t` is `(1 - house_edge) / t`.
+pub fn limbo_multiplier(draw: &[u8], house_edge: u16) -> Result<u64> {
+    require!(draw.len() >= 8, crate::errors::CasinoError::InvalidRandomnessSource);
+    
+    let mut bytes = [0u8; 8];
+    bytes.copy_from_slice(&draw[0..8]);
+    let sample = (u64::from_le_bytes(bytes) >> 12) as u128; // 52 bits of entropy
+    
+    let rtp = super::BASIS_POINTS.saturating_sub(house_edge as u64) as u128;
+    let multiplier = (rtp << 52) / (sample + 1);
+    
+    Ok(multiplier.min(super::LIMBO_MAX_TARGET as u128) as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{LIMBO_MAX_TARGET, LIMBO_MIN_TARGET};
+
+    const HOUSE_EDGE: u16 = 100; // 1%
+
+    /// Draw whose 52-bit sample is exactly `sample`
+    fn draw(sample: u64) -> [u8; 8] {
+        (sample << 12).to_le_bytes()
+    }
+
+    fn limbo_game(bet_amount: u64, target: u64) -> Game {
+        Game {
+            player: Pubkey::new_unique(),
+            casino: Pubkey::new_unique(),
+            mint: Pubkey::new_unique(),
+            game_type: GameType::Limbo,
+            bet_amount,
+            prediction: target.to_le_bytes().to_vec(),
+            side_bets: Vec::new(),
+            result: None,
+            provable_fair: ProvableFairData {
+                server_seed_hash: String::new(),
+                client_seed: String::new(),
+                nonce: 0,
+                server_seed: None,
+            },
+            status: GameStatus::Active,
+            created_at: 0,
+            resolved_at: None,
+            claimed_at: None,
+            expires_at: 0,
+            session_id: 0,
+            jackpot_contribution: 0,
+            jackpot_payout: 0,
+            jackpot_claimed: false,
+            house_edge: HOUSE_EDGE,
+            treasury_fee: 0,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn limbo_multiplier_spans_rtp_to_cap() {
+        // Largest sample gives the lowest multiplier, just the RTP itself
+        assert_eq!(limbo_multiplier(&draw((1 << 52) - 1), HOUSE_EDGE).unwrap(), 9900);
+        // Half way through the range doubles it
+        assert_eq!(limbo_multiplier(&draw((1 << 51) - 1), HOUSE_EDGE).unwrap(), 19800);
+        // Tiny samples are capped at the maximum target
+        assert_eq!(limbo_multiplier(&draw(0), HOUSE_EDGE).unwrap(), LIMBO_MAX_TARGET);
+    }
+
+    #[test]
+    fn limbo_multiplier_rejects_short_draws() {
+        assert!(limbo_multiplier(&[0u8; 7], HOUSE_EDGE).is_err());
+    }
+
+    #[test]
+    fn limbo_win_probability_matches_rtp() {
+        const SAMPLES: u64 = 1 << 16;
+        let step = (1u64 << 52) / SAMPLES;
+
+        for target in [LIMBO_MIN_TARGET, 20000, 100000] {
+            let wins = (0..SAMPLES)
+                .filter(|i| limbo_multiplier(&draw(i * step), HOUSE_EDGE).unwrap() >= target)
+                .count() as f64;
+            let expected = 9900.0 / target as f64;
+            let probability = wins / SAMPLES as f64;
+            assert!((probability - expected).abs() < 0.001, "target {target}: {probability} vs {expected}");
+        }
+    }
+
+    #[test]
+    fn limbo_pays_target_without_deducting_edge() {
+        let game = limbo_game(1_000, 20000);
+
+        let won = game.calculate_payout(&draw(1 << 40), HOUSE_EDGE).unwrap();
+        assert_eq!(won.multiplier, 20000);
+        assert_eq!(won.payout, 2_000);
+        assert_eq!(won.house_edge_taken, 0);
+
+        let lost = game.calculate_payout(&draw((1 << 52) - 1), HOUSE_EDGE).unwrap();
+        assert_eq!(lost.payout, 0);
+        assert_eq!(lost.house_edge_taken, 0);
+    }
+
+    #[test]
+    fn limbo_target_is_bounded() {
+        assert!(limbo_game(1_000, LIMBO_MIN_TARGET - 1).limbo_target().is_err());
+        assert!(limbo_game(1_000, LIMBO_MAX_TARGET + 1).limbo_target().is_err());
+        assert_eq!(limbo_game(1_000, 20000).potential_payout().unwrap(), 2_000);
+    }
+}
@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+use super::{GovernanceConfig, GovernanceProposalStatus, ParameterUpdate, GOVERNANCE_EXECUTION_WINDOW};
+
+/// Parameter change put to a vote of `Casino.token_mint` holders.
+///
+/// Votes are weighted by the tokens a voter escrows, which stay locked until the
+/// voting period ends. A proposal passes once it reaches quorum with more votes
+/// for than against, and has to be executed within `GOVERNANCE_EXECUTION_WINDOW`
+/// of the vote closing, after which it expires.
+#[account]
+pub struct GovernanceProposal {
+    /// Casino whose parameters change
+    pub casino: Pubkey,
+    /// Holder that created the proposal
+    pub proposer: Pubkey,
+    /// Proposer-chosen id, unique per proposer
+    pub proposal_id: u64,
+    /// Parameters set if the proposal passes
+    pub update: ParameterUpdate,
+    /// Escrowed tokens voting for
+    pub votes_for: u64,
+    /// Escrowed tokens voting against
+    pub votes_against: u64,
+    /// Current status
+    pub status: GovernanceProposalStatus,
+    /// Timestamp when proposal was created
+    pub created_at: i64,
+    /// End of the voting period
+    pub voting_ends_at: i64,
+    /// Timestamp when the outcome was applied
+    pub finalized_at: Option<i64>,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl GovernanceProposal {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // casino
+        32 + // proposer
+        8 + // proposal_id
+        ParameterUpdate::LEN + // update
+        8 + // votes_for
+        8 + // votes_against
+        1 + // status
+        8 + // created_at
+        8 + // voting_ends_at
+        1 + 8 + // finalized_at (optional)
+        1 + // bump
+        64; // padding for future fields
+
+    pub fn is_voting(&self, now: i64) -> bool {
+        self.status == GovernanceProposalStatus::Voting && now < self.voting_ends_at
+    }
+
+    /// Last moment a passed proposal can still be executed
+    pub fn execution_deadline(&self) -> i64 {
+        self.voting_ends_at + GOVERNANCE_EXECUTION_WINDOW
+    }
+
+    pub fn record_vote(&mut self, support: bool, weight: u64) -> Result<()> {
+        let tally = if support { &mut self.votes_for } else { &mut self.votes_against };
+        *tally = tally
+            .checked_add(weight)
+            .ok_or(crate::errors::CasinoError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    pub fn has_passed(&self, config: &GovernanceConfig) -> bool {
+        let turnout = self.votes_for.saturating_add(self.votes_against);
+        turnout >= config.quorum && self.votes_for > self.votes_against
+    }
+}
+
+/// A holder's vote on a governance proposal, owning the escrow of its tokens
+#[account]
+pub struct VoteRecord {
+    /// Proposal voted on
+    pub proposal: Pubkey,
+    /// Holder that cast the vote and gets the tokens back
+    pub voter: Pubkey,
+    /// Voting weight, the escrowed amount after any transfer fee
+    pub weight: u64,
+    /// Whether the vote is for the proposal
+    pub support: bool,
+    /// Timestamp when vote was cast
+    pub voted_at: i64,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl VoteRecord {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // proposal
+        32 + // voter
+        8 + // weight
+        1 + // support
+        8 + // voted_at
+        1 + // bump
+        32; // padding for future fields
+}
@@ -0,0 +1,170 @@
+use anchor_lang::prelude::*;
+use super::{Game, GameType, BASIS_POINTS};
+
+/// Progressive jackpot pool for a casino.
+///
+/// The pool is held in the casino's primary vault and reserved as liability, so it
+/// is never counted as free bankroll. Only primary-mint games feed it, and so only
+/// they can win it: the win is locked for the player when resolution triggers it,
+/// and stays reserved until claimed.
+#[account]
+pub struct Jackpot {
+    /// Casino this jackpot belongs to
+    pub casino: Pubkey,
+    /// Slice of every bet added to the pool, in basis points
+    pub contribution_bps: u16,
+    /// Share of the pool paid to a winner, in basis points
+    pub payout_bps: u16,
+    /// Odds of the hash trigger for any game (1 in N)
+    pub trigger_odds: u32,
+    /// Current size of the pool in token units
+    pub balance: u64,
+    /// Total contributed to the pool
+    pub total_contributed: u64,
+    /// Total paid out from the pool
+    pub total_paid: u64,
+    /// Number of jackpots won
+    pub wins: u64,
+    /// Most recent winner
+    pub last_winner: Option<Pubkey>,
+    /// Most recent jackpot payout
+    pub last_payout: u64,
+    /// Timestamp of the most recent win
+    pub last_won_at: Option<i64>,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl Jackpot {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // casino
+        2 + // contribution_bps
+        2 + // payout_bps
+        4 + // trigger_odds
+        8 + // balance
+        8 + // total_contributed
+        8 + // total_paid
+        8 + // wins
+        1 + 32 + // last_winner (optional)
+        8 + // last_payout
+        1 + 8 + // last_won_at (optional)
+        1 + // bump
+        64; // padding for future fields
+
+    pub fn validate_config(&self) -> Result<()> {
+        require!(
+            self.contribution_bps <= super::MAX_JACKPOT_CONTRIBUTION,
+            crate::errors::CasinoError::InvalidConfiguration
+        );
+        require!(
+            self.payout_bps > 0 && self.payout_bps as u64 <= BASIS_POINTS,
+            crate::errors::CasinoError::InvalidConfiguration
+        );
+        require!(self.trigger_odds > 0, crate::errors::CasinoError::InvalidConfiguration);
+        Ok(())
+    }
+
+    pub fn calculate_contribution(&self, bet_amount: u64) -> Result<u64> {
+        let contribution = (bet_amount as u128 * self.contribution_bps as u128) / BASIS_POINTS as u128;
+        u64::try_from(contribution).map_err(|_| crate::errors::CasinoError::ArithmeticOverflow.into())
+    }
+
+    /// Loads the casino's jackpot from its PDA, or None when none has been created.
+    /// The caller checks the address by seeds and writes changes back with `store`.
+    pub fn load(account: &AccountInfo) -> Result<Option<Jackpot>> {
+        if account.data_is_empty() {
+            return Ok(None);
+        }
+        require_keys_eq!(*account.owner, crate::ID, crate::errors::CasinoError::InvalidAccountOwner);
+        Jackpot::try_deserialize(&mut &account.try_borrow_data()?[..]).map(Some)
+    }
+
+    pub fn store(&self, account: &AccountInfo) -> Result<()> {
+        self.try_serialize(&mut &mut account.try_borrow_mut_data()?[..])
+    }
+
+    /// Adds the jackpot slice of a bet to the pool and returns it
+    pub fn contribute(&mut self, bet_amount: u64) -> Result<u64> {
+        let contribution = self.calculate_contribution(bet_amount)?;
+        self.add_to_pool(contribution)?;
+        Ok(contribution)
+    }
+
+    pub fn add_to_pool(&mut self, amount: u64) -> Result<()> {
+        self.balance = self.balance
+            .checked_add(amount)
+            .ok_or(crate::errors::CasinoError::ArithmeticOverflow)?;
+        self.total_contributed = self.total_contributed.saturating_add(amount);
+        Ok(())
+    }
+
+    /// Triple 7 in slots always hits; any game can also hit the rare hash condition
+    pub fn is_triggered(&self, game: &Game, server_seed: &str) -> bool {
+        let triple_seven = game.game_type == GameType::Slots &&
+            game.result.as_ref().map_or(false, |r| r.outcome.as_slice() == [7, 7, 7]);
+
+        triple_seven || game.jackpot_roll(server_seed) % self.trigger_odds == 0
+    }
+
+    pub fn calculate_payout(&self) -> u64 {
+        ((self.balance as u128 * self.payout_bps as u128) / BASIS_POINTS as u128) as u64
+    }
+
+    /// Takes the winner's share out of the pool, pricing it at the moment the
+    /// jackpot triggers, and returns it
+    pub fn lock_win(&mut self, winner: Pubkey, now: i64) -> u64 {
+        let payout = self.calculate_payout();
+        self.balance = self.balance.saturating_sub(payout);
+        self.wins = self.wins.saturating_add(1);
+        self.last_winner = Some(winner);
+        self.last_payout = payout;
+        self.last_won_at = Some(now);
+        payout
+    }
+
+    pub fn record_payout(&mut self, payout: u64) {
+        self.total_paid = self.total_paid.saturating_add(payout);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn jackpot(balance: u64) -> Jackpot {
+        Jackpot {
+            casino: Pubkey::new_unique(),
+            contribution_bps: 100,
+            payout_bps: 5000,
+            trigger_odds: 1000,
+            balance,
+            total_contributed: balance,
+            total_paid: 0,
+            wins: 0,
+            last_winner: None,
+            last_payout: 0,
+            last_won_at: None,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn win_is_priced_when_locked() {
+        let mut jackpot = jackpot(10_000);
+        let payout = jackpot.lock_win(Pubkey::new_unique(), 1);
+        assert_eq!(payout, 5_000);
+        assert_eq!(jackpot.balance, 5_000);
+
+        // Later contributions and wins leave the locked amount alone
+        jackpot.contribute(1_000_000).unwrap();
+        assert_eq!(jackpot.balance, 15_000);
+        assert_eq!(jackpot.lock_win(Pubkey::new_unique(), 2), 7_500);
+        assert_eq!(jackpot.wins, 2);
+    }
+
+    #[test]
+    fn contribution_of_large_bets_does_not_overflow() {
+        let jackpot = jackpot(0);
+        assert_eq!(jackpot.calculate_contribution(u64::MAX).unwrap(), u64::MAX / 100);
+    }
+}
@@ -0,0 +1,231 @@
+use anchor_lang::prelude::*;
+use super::{LP_VIRTUAL_LIQUIDITY, MAX_LP_COOLDOWN, MAX_LP_EPOCH, MIN_LP_EPOCH};
+
+/// Bankroll liquidity pool for a casino's primary vault.
+///
+/// LPs own the vault pro rata through the share mint. Shares are priced against
+/// the vault balance minus reserved liability and accrued treasury fees, so house
+/// profit and losses flow straight into share value.
+///
+/// Withdrawals are queued: shares are escrowed on request, and once the cooldown
+/// has passed they are redeemed at the value snapshotted at the next epoch
+/// boundary, or the current value if lower, so LPs cannot pull out ahead of a
+/// payout they can see coming nor leave a later loss to those who stay.
+///
+/// Share conversions count `LP_VIRTUAL_LIQUIDITY` extra tokens and shares on both
+/// sides, so tokens donated to a near-empty pool mostly accrue to the virtual
+/// position and inflating the share price to round later deposits down never pays.
+#[account]
+pub struct LiquidityPool {
+    /// Casino whose vault backs the shares
+    pub casino: Pubkey,
+    /// LP share mint, with the casino PDA as mint authority
+    pub share_mint: Pubkey,
+    /// Maximum principal LPs may have deposited (0 = uncapped)
+    pub deposit_cap: u64,
+    /// Principal currently deposited by LPs
+    pub total_deposits: u64,
+    /// Total paid out to LPs on withdrawal
+    pub total_withdrawn: u64,
+    /// Seconds between a withdrawal request and its earliest execution
+    pub cooldown_period: i64,
+    /// Length of a pricing epoch in seconds
+    pub epoch_duration: i64,
+    /// Number of epochs settled so far
+    pub current_epoch: u64,
+    /// Timestamp of the latest epoch boundary
+    pub epoch_started_at: i64,
+    /// Pool value snapshotted at the latest epoch boundary
+    pub epoch_pool_value: u64,
+    /// Share supply snapshotted at the latest epoch boundary
+    pub epoch_share_supply: u64,
+    /// Shares escrowed by pending withdrawal requests
+    pub pending_shares: u64,
+    /// Timestamp when pool was created
+    pub created_at: i64,
+    /// Last update timestamp
+    pub updated_at: i64,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl LiquidityPool {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // casino
+        32 + // share_mint
+        8 + // deposit_cap
+        8 + // total_deposits
+        8 + // total_withdrawn
+        8 + // cooldown_period
+        8 + // epoch_duration
+        8 + // current_epoch
+        8 + // epoch_started_at
+        8 + // epoch_pool_value
+        8 + // epoch_share_supply
+        8 + // pending_shares
+        8 + // created_at
+        8 + // updated_at
+        1 + // bump
+        64; // padding for future fields
+
+    pub fn validate_config(&self) -> Result<()> {
+        require!(
+            self.cooldown_period >= 0 && self.cooldown_period <= MAX_LP_COOLDOWN,
+            crate::errors::CasinoError::InvalidConfiguration
+        );
+        require!(
+            self.epoch_duration >= MIN_LP_EPOCH && self.epoch_duration <= MAX_LP_EPOCH,
+            crate::errors::CasinoError::InvalidConfiguration
+        );
+        Ok(())
+    }
+
+    pub fn can_settle_epoch(&self, now: i64) -> bool {
+        now >= self.epoch_started_at + self.epoch_duration
+    }
+
+    /// Snapshots the pool value that queued withdrawals are priced at
+    pub fn settle_epoch(&mut self, pool_value: u64, share_supply: u64, now: i64) {
+        self.current_epoch = self.current_epoch.saturating_add(1);
+        self.epoch_started_at = now;
+        self.epoch_pool_value = pool_value;
+        self.epoch_share_supply = share_supply;
+        self.updated_at = now;
+    }
+
+    /// Shares minted for a deposit of `amount` into a pool worth `pool_value`
+    pub fn shares_for_deposit(amount: u64, pool_value: u64, share_supply: u64) -> Result<u64> {
+        // Outstanding shares in a pool worth nothing would be diluted to nothing
+        require!(
+            share_supply == 0 || pool_value > 0,
+            crate::errors::CasinoError::InsufficientVaultFunds
+        );
+        let shares = (amount as u128 * (share_supply as u128 + LP_VIRTUAL_LIQUIDITY as u128))
+            / (pool_value as u128 + LP_VIRTUAL_LIQUIDITY as u128);
+        u64::try_from(shares).map_err(|_| crate::errors::CasinoError::ArithmeticOverflow.into())
+    }
+
+    /// Vault tokens owed for redeeming `shares` from a pool worth `pool_value`
+    pub fn amount_for_shares(shares: u64, pool_value: u64, share_supply: u64) -> Result<u64> {
+        require!(
+            shares > 0 && shares <= share_supply,
+            crate::errors::CasinoError::InvalidInstructionData
+        );
+        let amount = (shares as u128 * (pool_value as u128 + LP_VIRTUAL_LIQUIDITY as u128))
+            / (share_supply as u128 + LP_VIRTUAL_LIQUIDITY as u128);
+        Ok((amount as u64).min(pool_value))
+    }
+
+    pub fn record_deposit(&mut self, amount: u64) -> Result<()> {
+        self.total_deposits = self.total_deposits
+            .checked_add(amount)
+            .ok_or(crate::errors::CasinoError::ArithmeticOverflow)?;
+        if self.deposit_cap > 0 {
+            require!(
+                self.total_deposits <= self.deposit_cap,
+                crate::errors::CasinoError::DepositLimitExceeded
+            );
+        }
+        self.updated_at = Clock::get()?.unix_timestamp;
+        Ok(())
+    }
+
+    /// Retires the principal behind `shares` along with the payout
+    pub fn record_withdrawal(&mut self, shares: u64, share_supply: u64, amount: u64) -> Result<()> {
+        let principal = (self.total_deposits as u128 * shares as u128) / share_supply as u128;
+        self.total_deposits = self.total_deposits.saturating_sub(principal as u64);
+        self.total_withdrawn = self.total_withdrawn.saturating_add(amount);
+        self.pending_shares = self.pending_shares.saturating_sub(shares);
+        self.updated_at = Clock::get()?.unix_timestamp;
+        Ok(())
+    }
+}
+
+/// Queued LP withdrawal, holding shares in the pool escrow until executed
+#[account]
+pub struct WithdrawalRequest {
+    /// Pool the shares are redeemed from
+    pub pool: Pubkey,
+    /// Liquidity provider receiving the payout
+    pub provider: Pubkey,
+    /// Shares still to be redeemed
+    pub shares: u64,
+    /// Timestamp when request was made
+    pub requested_at: i64,
+    /// Earliest epoch boundary the request can be priced at
+    pub unlocks_at: i64,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl WithdrawalRequest {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // pool
+        32 + // provider
+        8 + // shares
+        8 + // requested_at
+        8 + // unlocks_at
+        1 + // bump
+        32; // padding for future fields
+
+    /// Executable once an epoch boundary has been settled after the cooldown
+    pub fn validate_executable(&self, pool: &LiquidityPool) -> Result<()> {
+        require!(
+            pool.epoch_started_at >= self.unlocks_at,
+            crate::errors::CasinoError::CooldownPeriodActive
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_deposit_mints_one_share_per_token() {
+        assert_eq!(LiquidityPool::shares_for_deposit(1_000_000, 0, 0).unwrap(), 1_000_000);
+        assert_eq!(LiquidityPool::amount_for_shares(1_000_000, 1_000_000, 1_000_000).unwrap(), 1_000_000);
+    }
+
+    #[test]
+    fn deposit_into_worthless_pool_is_rejected() {
+        assert!(LiquidityPool::shares_for_deposit(1_000_000, 0, 500).is_err());
+    }
+
+    #[test]
+    fn deposit_and_redeem_round_trip_never_profits() {
+        for (pool_value, share_supply) in [(1_000_000, 1_000_000), (3_000_000, 1_000_000), (700_000, 1_000_000)] {
+            let amount = 123_457;
+            let shares = LiquidityPool::shares_for_deposit(amount, pool_value, share_supply).unwrap();
+            let redeemed =
+                LiquidityPool::amount_for_shares(shares, pool_value + amount, share_supply + shares).unwrap();
+            assert!(redeemed <= amount);
+            assert!(amount - redeemed <= pool_value / share_supply + 1);
+        }
+    }
+
+    #[test]
+    fn donation_does_not_pay_the_first_depositor() {
+        // Attacker seeds one token, donates to the vault, then a victim deposits
+        let donation = 10_000_000;
+        let attacker_shares = LiquidityPool::shares_for_deposit(1, 0, 0).unwrap();
+        let pool_value = 1 + donation;
+
+        let victim_deposit = 1_000_000;
+        let victim_shares = LiquidityPool::shares_for_deposit(victim_deposit, pool_value, attacker_shares).unwrap();
+        assert!(victim_shares > 0);
+
+        let share_supply = attacker_shares + victim_shares;
+        let pool_value = pool_value + victim_deposit;
+        let attacker_out = LiquidityPool::amount_for_shares(attacker_shares, pool_value, share_supply).unwrap();
+        assert!(attacker_out < 1 + donation);
+    }
+
+    #[test]
+    fn redemption_never_exceeds_pool_value() {
+        let share_supply = 100;
+        let pool_value = 50;
+        assert_eq!(LiquidityPool::amount_for_shares(share_supply, pool_value, share_supply).unwrap(), pool_value);
+    }
+}
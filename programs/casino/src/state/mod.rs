@@ -1,16 +1,38 @@
 use anchor_lang::prelude::*;
 
 pub mod casino;
+pub mod casino_mint;
+pub mod duel;
+pub mod roulette;
+pub mod side_bet;
+pub mod baccarat;
 pub mod game;
-pub mod player;
-pub mod tournament;
+pub mod seed_commitment;
+pub mod governance;
+pub mod jackpot;
+pub mod liquidity_pool;
+pub mod multisig;
+pub mod price_cache;
+pub mod roles;
+pub mod player_ban;
 
 pub use casino::*;
+pub use casino_mint::*;
+pub use duel::*;
+pub use roulette::*;
+pub use side_bet::*;
+pub use baccarat::*;
 pub use game::*;
-pub use player::*;
-pub use tournament::*;
+pub use seed_commitment::*;
+pub use governance::*;
+pub use jackpot::*;
+pub use liquidity_pool::*;
+pub use multisig::*;
+pub use price_cache::*;
+pub use roles::*;
+pub use player_ban::*;
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
 pub enum GameType {
     CoinFlip,
     DiceRoll,
@@ -20,9 +42,11 @@ pub enum GameType {
     Poker,
     Lottery,
     SportsBet,
+    Limbo,
+    Baccarat,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
 pub enum GameStatus {
     Created,
     Active,
@@ -33,6 +57,133 @@ pub enum GameStatus {
     Expired,
 }
 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum DuelStatus {
+    Open,
+    Matched,
+    Settled,
+    Cancelled,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum RouletteRoundStatus {
+    Open,
+    Spun,
+    Refunded,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum RouletteBetKind {
+    Straight,
+    Red,
+    Black,
+    Odd,
+    Even,
+    Low,
+    High,
+    Dozen,
+    Column,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum SideBetKind {
+    PlayerPair,
+    BankerPair,
+    LuckyNumber,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum PriceSourceKind {
+    Switchboard,
+    AmmTwap,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum PoolKind {
+    TokenReserves,
+    Mock,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub enum PauseReason {
+    #[default]
+    Unspecified,
+    SecurityIncident,
+    OracleFailure,
+    VaultImbalance,
+    OperatorError,
+    Upgrade,
+    IssueResolved,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Role {
+    Operator,
+    Resolver,
+    Treasurer,
+    Pauser,
+    Compliance,
+}
+
+pub const ROLE_COUNT: usize = 5;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum ProposalStatus {
+    Active,
+    Executed,
+    Cancelled,
+}
+
+/// Admin action carried out by the multisig once a proposal reaches its threshold
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub enum ProposalAction {
+    /// Pay `amount` out of the treasury to the `recipient` token account (treasurer role)
+    WithdrawTreasury { amount: u64, recipient: Pubkey },
+    /// Queue a timelocked house edge, fee or max bet change for the casino (operator role)
+    ConfigChange {
+        house_edge: Option<u16>,
+        treasury_fee: Option<u16>,
+        cancellation_fee: Option<u16>,
+        max_bet: Option<u64>,
+    },
+    /// Propose a new holder for a role, or for the casino authority (casino authority)
+    ProposeAuthority { role: Option<Role>, proposed: Pubkey },
+    /// Accept a role, or the casino authority, proposed to the multisig
+    AcceptAuthority { role: Option<Role> },
+    /// Replace the signer set and threshold, invalidating other open proposals
+    SetSigners { signers: Vec<Pubkey>, threshold: u8 },
+    /// Call any casino instruction with the multisig as signer, through
+    /// `invoke_proposal`; `accounts_hash` commits to the accounts it is called with
+    Invoke { data: Vec<u8>, accounts_hash: [u8; 32] },
+}
+
+impl ProposalAction {
+    // Largest variant is SetSigners, Invoke is capped below it by MAX_INVOKE_DATA
+    pub const LEN: usize = 1 + (4 + 32 * MAX_MULTISIG_SIGNERS) + 1;
+
+    pub fn validate(&self) -> Result<()> {
+        match self {
+            ProposalAction::SetSigners { signers, threshold } => Multisig::validate_signers(signers, *threshold),
+            ProposalAction::Invoke { data, .. } => {
+                require!(
+                    data.len() >= 8 && data.len() <= MAX_INVOKE_DATA,
+                    crate::errors::CasinoError::InvalidInstructionData
+                );
+                Ok(())
+            },
+            _ => Ok(()),
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum GovernanceProposalStatus {
+    Voting,
+    Executed,
+    Defeated,
+    Expired,
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
 pub enum TournamentStatus {
     Created,
@@ -48,6 +199,14 @@ pub struct GameResult {
     pub payout: u64,
     pub house_edge_taken: u64,
     pub treasury_fee_taken: u64,
+    pub side_bet_payouts: Vec<u64>, // One entry per side bet, included in payout
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SideBet {
+    pub kind: SideBetKind,
+    pub selection: u8,
+    pub amount: u64,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
@@ -70,6 +229,336 @@ pub struct PlayerStats {
     pub experience: u64,
 }
 
+/// Mint and vault a bet is settled through
+#[derive(Clone, Copy)]
+pub struct VaultRef {
+    pub mint: Pubkey,
+    pub vault: Pubkey,
+}
+
+impl VaultRef {
+    pub fn is_native(&self) -> bool {
+        self.mint == spl_token::native_mint::ID
+    }
+}
+
+/// Bet limits expressed in USD, converted with a price source at bet time
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct UsdLimits {
+    /// Kind of account behind `price_feed`
+    pub source: PriceSourceKind,
+    /// Switchboard aggregator or `PriceCache` pricing the casino token in USD
+    pub price_feed: Pubkey,
+    /// Minimum bet in micro-USD
+    pub min_bet_usd: u64,
+    /// Maximum bet in micro-USD
+    pub max_bet_usd: u64,
+    /// Maximum age of the latest confirmed round or crank in seconds
+    pub max_staleness: u32,
+    /// Maximum standard deviation (Switchboard) or spot deviation from the TWAP
+    /// (AMM) relative to the price, in basis points
+    pub max_confidence_bps: u16,
+}
+
+impl UsdLimits {
+    pub const LEN: usize = 1 + 32 + 8 + 8 + 4 + 2;
+
+    pub fn validate_config(&self) -> Result<()> {
+        require!(
+            self.min_bet_usd > 0 && self.min_bet_usd <= self.max_bet_usd,
+            crate::errors::CasinoError::InvalidConfiguration
+        );
+        require!(self.max_staleness > 0, crate::errors::CasinoError::InvalidConfiguration);
+        require!(
+            self.max_confidence_bps > 0 && self.max_confidence_bps as u64 <= BASIS_POINTS,
+            crate::errors::CasinoError::InvalidConfiguration
+        );
+        Ok(())
+    }
+
+    pub fn validate_bet_value(&self, usd_value: u64) -> Result<()> {
+        require!(usd_value >= self.min_bet_usd, crate::errors::CasinoError::BetTooSmall);
+        require!(usd_value <= self.max_bet_usd, crate::errors::CasinoError::BetTooLarge);
+        Ok(())
+    }
+}
+
+/// Drawdown circuit breaker that stops new bets after unusual house losses.
+///
+/// P&L is tracked over a rolling window of `window` seconds, kept as
+/// `DRAWDOWN_BUCKETS` buckets so losses just before and after a bucket boundary
+/// still count together. Once tripped, only new bets are refused; resolve, claim
+/// and refund flows keep working until the authority resets it.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct CircuitBreaker {
+    /// Net house loss within a window that trips the breaker, in token units (0 = off)
+    pub max_drawdown: u64,
+    /// Length of the P&L window in seconds
+    pub window: i64,
+    /// Single payout that trips the breaker, as a share of the vault in basis points (0 = off)
+    pub max_payout_share: u16,
+    /// Number of the most recent bucket, counted in bucket widths since the epoch
+    pub bucket_index: i64,
+    /// Net house P&L per bucket, indexed by bucket number modulo `DRAWDOWN_BUCKETS`
+    pub buckets: [i64; DRAWDOWN_BUCKETS],
+    /// Net house P&L over the rolling window
+    pub window_pnl: i64,
+    /// When the breaker tripped, if it has
+    pub tripped_at: Option<i64>,
+}
+
+impl CircuitBreaker {
+    pub const LEN: usize = 8 + 8 + 2 + 8 + 8 * DRAWDOWN_BUCKETS + 8 + (1 + 8);
+
+    pub fn validate_config(&self) -> Result<()> {
+        if self.max_drawdown > 0 {
+            require!(
+                self.window >= MIN_DRAWDOWN_WINDOW && self.window <= MAX_DRAWDOWN_WINDOW,
+                crate::errors::CasinoError::InvalidConfiguration
+            );
+        }
+        require!(
+            self.max_payout_share as u64 <= BASIS_POINTS,
+            crate::errors::CasinoError::InvalidConfiguration
+        );
+        Ok(())
+    }
+
+    pub fn is_tripped(&self) -> bool {
+        self.tripped_at.is_some()
+    }
+
+    /// Adds a settled bet to the rolling P&L, tripping on excessive drawdown
+    pub fn record_result(&mut self, wagered: u64, paid: u64, now: i64) {
+        self.roll(now);
+        let pnl = (wagered as i64).saturating_sub(paid as i64);
+        let slot = self.bucket_index.rem_euclid(DRAWDOWN_BUCKETS as i64) as usize;
+        self.buckets[slot] = self.buckets[slot].saturating_add(pnl);
+        self.window_pnl = self.window_pnl.saturating_add(pnl);
+
+        if self.max_drawdown > 0 && self.window_pnl < -(self.max_drawdown.min(i64::MAX as u64) as i64) {
+            self.trip(now);
+        }
+    }
+
+    /// Trips when a single payout takes too large a share of the vault it is paid from
+    pub fn record_payout(&mut self, payout: u64, vault_balance: u64, now: i64) {
+        let limit = (vault_balance as u128 * self.max_payout_share as u128) / BASIS_POINTS as u128;
+        if self.max_payout_share > 0 && payout as u128 > limit {
+            self.trip(now);
+        }
+    }
+
+    /// Changes the window length, carrying the current P&L into the new current
+    /// bucket so a resize never forgets recent losses
+    pub fn set_window(&mut self, window: i64, now: i64) {
+        self.roll(now);
+        let window_pnl = self.window_pnl;
+        self.window = window;
+        self.buckets = [0; DRAWDOWN_BUCKETS];
+        self.bucket_index = now / self.bucket_width();
+        let slot = self.bucket_index.rem_euclid(DRAWDOWN_BUCKETS as i64) as usize;
+        self.buckets[slot] = window_pnl;
+    }
+
+    pub fn reset(&mut self, now: i64) {
+        self.tripped_at = None;
+        self.buckets = [0; DRAWDOWN_BUCKETS];
+        self.bucket_index = now / self.bucket_width();
+        self.window_pnl = 0;
+    }
+
+    fn bucket_width(&self) -> i64 {
+        (self.window / DRAWDOWN_BUCKETS as i64).max(1)
+    }
+
+    /// Advances to the bucket containing `now`, dropping buckets that left the window
+    fn roll(&mut self, now: i64) {
+        let index = now / self.bucket_width();
+        let elapsed = index.saturating_sub(self.bucket_index);
+        if elapsed <= 0 {
+            return;
+        }
+        for step in 1..=elapsed.min(DRAWDOWN_BUCKETS as i64) {
+            let slot = (self.bucket_index + step).rem_euclid(DRAWDOWN_BUCKETS as i64) as usize;
+            self.window_pnl = self.window_pnl.saturating_sub(self.buckets[slot]);
+            self.buckets[slot] = 0;
+        }
+        self.bucket_index = index;
+    }
+
+    fn trip(&mut self, now: i64) {
+        if self.tripped_at.is_none() {
+            self.tripped_at = Some(now);
+        }
+    }
+}
+
+/// Who last paused and resumed the casino, and why
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct PauseRecord {
+    pub paused_by: Pubkey,
+    pub paused_at: i64,
+    /// Earliest time the pause can be lifted, fixed from the unpause delay when pausing
+    pub unpause_at: i64,
+    pub pause_reason: PauseReason,
+    pub resumed_by: Pubkey,
+    pub resumed_at: i64,
+    pub resume_reason: PauseReason,
+}
+
+impl PauseRecord {
+    pub const LEN: usize = 32 + 8 + 8 + 1 + 32 + 8 + 1;
+}
+
+/// Announced period during which new bets are refused while settlement continues.
+/// Windows are announced at least `MIN_MAINTENANCE_NOTICE` ahead; unannounced halts
+/// go through an emergency pause instead.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct MaintenanceWindow {
+    pub starts_at: i64,
+    pub ends_at: i64,
+}
+
+impl MaintenanceWindow {
+    pub const LEN: usize = 8 + 8;
+
+    pub fn validate(&self, now: i64) -> Result<()> {
+        require!(
+            self.starts_at < self.ends_at && self.starts_at >= now + MIN_MAINTENANCE_NOTICE,
+            crate::errors::CasinoError::InvalidTimestamp
+        );
+        require!(
+            self.ends_at - self.starts_at <= MAX_MAINTENANCE_DURATION,
+            crate::errors::CasinoError::InvalidConfiguration
+        );
+        Ok(())
+    }
+
+    pub fn is_active(&self, now: i64) -> bool {
+        now >= self.starts_at && now < self.ends_at
+    }
+}
+
+/// Economic parameter change queued until `effective_at`, between
+/// `CONFIG_TIMELOCK` and `MAX_CONFIG_DELAY` after it was announced
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct PendingConfigChange {
+    /// New house edge; Some(None) clears a mint override back to the casino default
+    pub house_edge: Option<Option<u16>>,
+    /// New treasury fee; Some(None) clears a mint override back to the casino default
+    pub treasury_fee: Option<Option<u16>>,
+    pub cancellation_fee: Option<u16>,
+    /// New max bet; raising it has to wait out the timelock like a fee increase
+    pub max_bet: Option<u64>,
+    pub queued_at: i64,
+    pub effective_at: i64,
+    /// Queued by a passed governance proposal, so the operator cannot cancel it
+    pub by_governance: bool,
+}
+
+impl PendingConfigChange {
+    pub const LEN: usize = (1 + 1 + 2) + (1 + 1 + 2) + (1 + 2) + (1 + 8) + 8 + 8 + 1;
+
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.house_edge.is_some()
+                || self.treasury_fee.is_some()
+                || self.cancellation_fee.is_some()
+                || self.max_bet.is_some(),
+            crate::errors::CasinoError::InvalidConfiguration
+        );
+        require!(
+            self.effective_at >= self.queued_at + CONFIG_TIMELOCK
+                && self.effective_at <= self.queued_at + MAX_CONFIG_DELAY,
+            crate::errors::CasinoError::InvalidTimestamp
+        );
+        if let Some(Some(house_edge)) = self.house_edge {
+            require!(
+                (MIN_HOUSE_EDGE..=MAX_HOUSE_EDGE).contains(&house_edge),
+                crate::errors::CasinoError::InvalidHouseEdge
+            );
+        }
+        if let Some(Some(treasury_fee)) = self.treasury_fee {
+            require!(treasury_fee <= MAX_TREASURY_FEE, crate::errors::CasinoError::InvalidTreasuryFee);
+        }
+        if let Some(cancellation_fee) = self.cancellation_fee {
+            require!(
+                (MIN_CANCELLATION_FEE..=MAX_CANCELLATION_FEE).contains(&cancellation_fee),
+                crate::errors::CasinoError::InvalidConfiguration
+            );
+        }
+        Ok(())
+    }
+
+    pub fn is_effective(&self, now: i64) -> bool {
+        now >= self.effective_at
+    }
+
+    /// Whether the change drops a mint override, which only mints have
+    pub fn clears_override(&self) -> bool {
+        self.house_edge == Some(None) || self.treasury_fee == Some(None)
+    }
+}
+
+/// Token-holder governance settings, fixed when the casino is created
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct GovernanceConfig {
+    /// Escrowed votes, for and against, a proposal needs to be decided
+    pub quorum: u64,
+    /// Shortest voting period a proposal may use, in seconds
+    pub min_voting_period: i64,
+}
+
+impl GovernanceConfig {
+    pub const LEN: usize = 8 + 8;
+
+    pub fn validate(&self) -> Result<()> {
+        require!(self.quorum > 0, crate::errors::CasinoError::InvalidConfiguration);
+        require!(
+            self.min_voting_period >= MIN_VOTING_PERIOD && self.min_voting_period <= MAX_VOTING_PERIOD,
+            crate::errors::CasinoError::InvalidConfiguration
+        );
+        Ok(())
+    }
+}
+
+/// Parameter changes shared by admin config updates and governance proposals
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct ParameterUpdate {
+    pub house_edge: Option<u16>,
+    pub max_bet: Option<u64>,
+    pub treasury_fee: Option<u16>,
+}
+
+impl ParameterUpdate {
+    pub const LEN: usize = (1 + 2) + (1 + 8) + (1 + 2);
+
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.house_edge.is_some() || self.max_bet.is_some() || self.treasury_fee.is_some(),
+            crate::errors::CasinoError::InvalidConfiguration
+        );
+        if let Some(house_edge) = self.house_edge {
+            require!(
+                (MIN_HOUSE_EDGE..=MAX_HOUSE_EDGE).contains(&house_edge),
+                crate::errors::CasinoError::InvalidHouseEdge
+            );
+        }
+        if let Some(treasury_fee) = self.treasury_fee {
+            require!(treasury_fee <= MAX_TREASURY_FEE, crate::errors::CasinoError::InvalidTreasuryFee);
+        }
+        Ok(())
+    }
+}
+
+/// Cumulative price snapshot used to compute a TWAP
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct PriceObservation {
+    pub timestamp: i64,
+    pub cumulative_price: u128,
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct CasinoStats {
     pub total_games: u64,
@@ -79,20 +568,81 @@ pub struct CasinoStats {
     pub active_players: u32,
     pub house_edge_collected: u64,
     pub treasury_fees_collected: u64,
+    pub jackpot_contributions: u64,
+    pub jackpot_payouts: u64,
+    pub games_expired: u64,
+    /// Bets valued at their USD price when placed, in micro-USD
+    pub volume_usd: u64,
+    /// Jackpot wins valued at their USD price when claimed, in micro-USD
+    pub jackpot_payouts_usd: u64,
 }
 
 // Constants
 pub const MAX_GAME_DURATION: i64 = 3600; // 1 hour
+pub const GAME_CANCEL_WINDOW: i64 = 30; // 30 seconds after creation
+pub const GAME_CLOSE_GRACE_PERIOD: i64 = 86400; // 1 day before anyone can close a settled game
 pub const MAX_TOURNAMENT_DURATION: i64 = 86400 * 7; // 1 week
+pub const DUEL_JOIN_TIMEOUT: i64 = 3600; // 1 hour before an unmatched duel can be cancelled
+pub const DUEL_REVEAL_TIMEOUT: i64 = 600; // 10 minutes for both players to reveal seeds
+pub const MAX_ROULETTE_BETTING_WINDOW: i64 = 3600; // 1 hour
+pub const ROULETTE_SPIN_DEADLINE: i64 = 600; // 10 minutes after betting closes before bets can be refunded
+pub const SLOT_DURATION_MS: u64 = 400; // target slot time, for converting windows to slots
+pub const MIN_TWAP_WINDOW: i64 = 300; // 5 minutes
+pub const MAX_TWAP_WINDOW: i64 = 86400; // 1 day
+pub const TWAP_OBSERVATIONS: usize = 8;
+pub const MAX_LP_COOLDOWN: i64 = 86400 * 30; // 30 days
+pub const MIN_LP_EPOCH: i64 = 3600; // 1 hour
+pub const MAX_LP_EPOCH: i64 = 86400 * 7; // 1 week
+pub const LP_VIRTUAL_LIQUIDITY: u64 = 1_000; // token units and shares
+pub const MAX_CLIENT_SEED_LEN: usize = 64;
+pub const MAX_SIDE_BETS: usize = 3;
 pub const MIN_HOUSE_EDGE: u16 = 50; // 0.5%
 pub const MAX_HOUSE_EDGE: u16 = 1000; // 10%
 pub const MAX_TREASURY_FEE: u16 = 500; // 5%
+pub const MAX_JACKPOT_CONTRIBUTION: u16 = 500; // 5%
+pub const MIN_CANCELLATION_FEE: u16 = 50; // 0.5%
+pub const MAX_CANCELLATION_FEE: u16 = 500; // 5%
+pub const MAX_KELLY_FRACTION: u16 = 1000; // 10% of free bankroll on a single bet
+pub const MAX_UNPAUSE_DELAY: i64 = 86400 * 7; // 1 week
+pub const MAX_MAINTENANCE_DURATION: i64 = 86400; // 1 day
+pub const MIN_MAINTENANCE_NOTICE: i64 = 3600; // 1 hour
+pub const CONFIG_TIMELOCK: i64 = 86400 * 2; // 48 hours
+pub const MAX_CONFIG_DELAY: i64 = 86400 * 30; // 30 days
+pub const MAX_MULTISIG_SIGNERS: usize = 10;
+pub const MAX_INVOKE_DATA: usize = 256;
+pub const MAX_PROPOSAL_TTL: i64 = 86400 * 7; // 1 week
+pub const MIN_VOTING_PERIOD: i64 = 86400; // 1 day
+pub const MAX_VOTING_PERIOD: i64 = 86400 * 30; // 30 days
+pub const GOVERNANCE_EXECUTION_WINDOW: i64 = 86400 * 7; // 1 week
+pub const MIN_DRAWDOWN_WINDOW: i64 = 3600; // 1 hour
+pub const MAX_DRAWDOWN_WINDOW: i64 = 86400 * 30; // 30 days
+pub const DRAWDOWN_BUCKETS: usize = 12;
 pub const BASIS_POINTS: u64 = 10000;
+pub const USD_DECIMALS: u32 = 6; // USD amounts are stored in micro-USD
 
 // Seeds for PDA derivation
 pub const CASINO_SEED: &[u8] = b"casino";
+pub const CASINO_MINT_SEED: &[u8] = b"casino_mint";
 pub const GAME_SEED: &[u8] = b"game";
+pub const SEED_COMMITMENT_SEED: &[u8] = b"seed_commitment";
+pub const GOVERNANCE_PROPOSAL_SEED: &[u8] = b"governance_proposal";
+pub const GOVERNANCE_VOTE_SEED: &[u8] = b"governance_vote";
+pub const GOVERNANCE_ESCROW_SEED: &[u8] = b"governance_escrow";
+pub const JACKPOT_SEED: &[u8] = b"jackpot";
+pub const LIQUIDITY_POOL_SEED: &[u8] = b"liquidity_pool";
+pub const LP_MINT_SEED: &[u8] = b"lp_mint";
+pub const LP_ESCROW_SEED: &[u8] = b"lp_escrow";
+pub const LP_WITHDRAWAL_SEED: &[u8] = b"lp_withdrawal";
+pub const MULTISIG_SEED: &[u8] = b"multisig";
+pub const PROPOSAL_SEED: &[u8] = b"proposal";
+pub const DUEL_SEED: &[u8] = b"duel";
+pub const DUEL_ESCROW_SEED: &[u8] = b"duel_escrow";
+pub const ROULETTE_ROUND_SEED: &[u8] = b"roulette_round";
+pub const ROULETTE_BET_SEED: &[u8] = b"roulette_bet";
+pub const PRICE_CACHE_SEED: &[u8] = b"price_cache";
+pub const ROLES_SEED: &[u8] = b"roles";
 pub const PLAYER_SEED: &[u8] = b"player";
+pub const PLAYER_BAN_SEED: &[u8] = b"player_ban";
 pub const TOURNAMENT_SEED: &[u8] = b"tournament";
 pub const TREASURY_SEED: &[u8] = b"treasury";
 pub const VAULT_SEED: &[u8] = b"vault";
@@ -103,3 +653,218 @@ pub const DICE_MAX_PAYOUT: u64 = 98000; // 9.8x max payout
 pub const SLOTS_MAX_PAYOUT: u64 = 250000; // 25x max payout
 pub const BLACKJACK_PAYOUT: u64 = 20000; // 2x for blackjack
 pub const ROULETTE_STRAIGHT_PAYOUT: u64 = 350000; // 35x for straight bet
+pub const ROULETTE_DOZEN_PAYOUT: u64 = 30000; // 3x for dozen and column bets
+pub const ROULETTE_EVEN_MONEY_PAYOUT: u64 = 20000; // 2x for red/black, odd/even, low/high
+pub const ROULETTE_POCKETS: usize = 37; // European wheel, 0-36
+pub const LIMBO_MIN_TARGET: u64 = 10100; // 1.01x minimum target
+pub const LIMBO_MAX_TARGET: u64 = 10_000_000_000; // 1,000,000x maximum target
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HOUR: i64 = 3600;
+
+    fn breaker(max_drawdown: u64, window: i64) -> CircuitBreaker {
+        let mut breaker = CircuitBreaker { max_drawdown, ..CircuitBreaker::default() };
+        breaker.set_window(window, 0);
+        breaker
+    }
+
+    #[test]
+    fn circuit_breaker_trips_on_losses_straddling_a_bucket_boundary() {
+        let mut breaker = breaker(1_000, 12 * HOUR);
+        breaker.record_result(0, 600, HOUR - 1);
+        breaker.record_result(0, 600, HOUR + 1);
+        assert!(breaker.is_tripped());
+        assert_eq!(breaker.window_pnl, -1_200);
+    }
+
+    #[test]
+    fn circuit_breaker_forgets_losses_older_than_the_window() {
+        let mut breaker = breaker(1_000, 12 * HOUR);
+        breaker.record_result(0, 600, 0);
+        breaker.record_result(0, 600, 12 * HOUR);
+        assert!(!breaker.is_tripped());
+        assert_eq!(breaker.window_pnl, -600);
+
+        // Everything expires after a long quiet spell
+        breaker.record_result(100, 0, 100 * HOUR);
+        assert_eq!(breaker.window_pnl, 100);
+    }
+
+    #[test]
+    fn circuit_breaker_nets_wins_against_losses() {
+        let mut breaker = breaker(1_000, 12 * HOUR);
+        breaker.record_result(500, 0, HOUR);
+        breaker.record_result(0, 1_400, 2 * HOUR);
+        assert!(!breaker.is_tripped());
+        breaker.record_result(0, 200, 3 * HOUR);
+        assert!(breaker.is_tripped());
+    }
+
+    #[test]
+    fn circuit_breaker_resize_keeps_recent_losses() {
+        let mut breaker = breaker(1_000, 12 * HOUR);
+        breaker.record_result(0, 900, HOUR);
+        breaker.set_window(24 * HOUR, 2 * HOUR);
+        assert_eq!(breaker.window_pnl, -900);
+        breaker.record_result(0, 200, 3 * HOUR);
+        assert!(breaker.is_tripped());
+    }
+
+    #[test]
+    fn circuit_breaker_reset_clears_the_window() {
+        let mut breaker = breaker(1_000, 12 * HOUR);
+        breaker.record_result(0, 2_000, HOUR);
+        assert!(breaker.is_tripped());
+        breaker.reset(2 * HOUR);
+        assert!(!breaker.is_tripped());
+        assert_eq!(breaker.window_pnl, 0);
+        breaker.record_result(0, 500, 3 * HOUR);
+        assert!(!breaker.is_tripped());
+    }
+
+    #[test]
+    fn circuit_breaker_trips_on_oversized_payout() {
+        let mut breaker = CircuitBreaker { max_payout_share: 1_000, ..CircuitBreaker::default() };
+        breaker.record_payout(1_000, 10_000, 0);
+        assert!(!breaker.is_tripped());
+        breaker.record_payout(1_001, 10_000, 0);
+        assert!(breaker.is_tripped());
+    }
+
+    #[test]
+    fn maintenance_window_needs_notice() {
+        let now = 10 * HOUR;
+        let window = |starts_at: i64| MaintenanceWindow { starts_at, ends_at: starts_at + HOUR };
+
+        assert!(window(now).validate(now).is_err());
+        assert!(window(now + MIN_MAINTENANCE_NOTICE - 1).validate(now).is_err());
+        assert!(window(now + MIN_MAINTENANCE_NOTICE).validate(now).is_ok());
+    }
+
+    #[test]
+    fn maintenance_window_duration_is_capped() {
+        let starts_at = MIN_MAINTENANCE_NOTICE;
+        let window = |ends_at: i64| MaintenanceWindow { starts_at, ends_at };
+
+        assert!(window(starts_at).validate(0).is_err());
+        assert!(window(starts_at + MAX_MAINTENANCE_DURATION).validate(0).is_ok());
+        assert!(window(starts_at + MAX_MAINTENANCE_DURATION + 1).validate(0).is_err());
+    }
+
+    fn change(house_edge: Option<Option<u16>>, treasury_fee: Option<Option<u16>>) -> PendingConfigChange {
+        PendingConfigChange {
+            house_edge,
+            treasury_fee,
+            cancellation_fee: None,
+            max_bet: None,
+            queued_at: 0,
+            effective_at: CONFIG_TIMELOCK,
+            by_governance: false,
+        }
+    }
+
+    fn casino_mint(house_edge: Option<u16>, treasury_fee: Option<u16>) -> CasinoMint {
+        CasinoMint {
+            casino: Pubkey::new_unique(),
+            mint: Pubkey::new_unique(),
+            vault: Pubkey::new_unique(),
+            min_bet: 1,
+            max_bet: 100,
+            max_payout: 0,
+            house_edge,
+            treasury_fee,
+            pending_config: None,
+            is_active: true,
+            reserved_liability: 0,
+            treasury_owed: 0,
+            circuit_breaker: CircuitBreaker::default(),
+            stats: CasinoStats {
+                total_games: 0,
+                total_volume: 0,
+                total_profit: 0,
+                total_payouts: 0,
+                active_players: 0,
+                house_edge_collected: 0,
+                treasury_fees_collected: 0,
+                jackpot_contributions: 0,
+                jackpot_payouts: 0,
+                games_expired: 0,
+                volume_usd: 0,
+                jackpot_payouts_usd: 0,
+            },
+            created_at: 0,
+            updated_at: 0,
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn config_change_effective_at_is_bounded() {
+        let mut change = change(Some(Some(200)), None);
+        assert!(change.validate().is_ok());
+
+        change.effective_at = CONFIG_TIMELOCK - 1;
+        assert!(change.validate().is_err());
+        change.effective_at = MAX_CONFIG_DELAY;
+        assert!(change.validate().is_ok());
+        change.effective_at = MAX_CONFIG_DELAY + 1;
+        assert!(change.validate().is_err());
+        assert!(!change.is_effective(MAX_CONFIG_DELAY));
+        assert!(change.is_effective(MAX_CONFIG_DELAY + 1));
+    }
+
+    #[test]
+    fn config_change_must_change_something_in_range() {
+        assert!(change(None, None).validate().is_err());
+        assert!(change(Some(Some(MAX_HOUSE_EDGE + 1)), None).validate().is_err());
+        assert!(change(Some(Some(MIN_HOUSE_EDGE - 1)), None).validate().is_err());
+        assert!(change(None, Some(Some(MAX_TREASURY_FEE + 1))).validate().is_err());
+        assert!(change(Some(None), None).validate().is_ok());
+    }
+
+    #[test]
+    fn mint_config_change_sets_and_clears_overrides() {
+        let mut casino_mint = casino_mint(Some(100), Some(50));
+
+        casino_mint.queue_config_change(change(Some(None), Some(Some(300)))).unwrap();
+        assert!(casino_mint.queue_config_change(change(Some(Some(200)), None)).is_err());
+
+        let pending = casino_mint.pending_config.take().unwrap();
+        assert!(pending.clears_override());
+        casino_mint.apply_config_change(&pending).unwrap();
+        assert_eq!(casino_mint.house_edge, None);
+        assert_eq!(casino_mint.treasury_fee, Some(300));
+    }
+
+    #[test]
+    fn mint_config_change_leaves_untouched_overrides() {
+        let mut casino_mint = casino_mint(Some(100), None);
+
+        casino_mint.apply_config_change(&change(None, Some(Some(20)))).unwrap();
+        assert_eq!(casino_mint.house_edge, Some(100));
+        assert_eq!(casino_mint.treasury_fee, Some(20));
+    }
+
+    #[test]
+    fn mint_config_change_rejects_cancellation_fee() {
+        let mut change = change(None, None);
+        change.cancellation_fee = Some(100);
+        assert!(change.validate().is_ok());
+        assert!(casino_mint(None, None).queue_config_change(change).is_err());
+    }
+
+    #[test]
+    fn mint_config_change_raises_max_bet_above_min_only() {
+        let mut change = change(None, None);
+        change.max_bet = Some(500);
+        let mut casino_mint = casino_mint(None, None);
+        casino_mint.apply_config_change(&change).unwrap();
+        assert_eq!(casino_mint.max_bet, 500);
+
+        change.max_bet = Some(0);
+        assert!(casino_mint.apply_config_change(&change).is_err());
+    }
+}
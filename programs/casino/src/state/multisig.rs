@@ -0,0 +1,270 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::AccountMeta;
+use sha2::{Digest, Sha256};
+use super::{ProposalAction, ProposalStatus, MAX_MULTISIG_SIGNERS};
+
+/// M-of-N signer set for a casino.
+///
+/// The multisig acts by holding roles or the casino authority itself: once a key is
+/// handed to this PDA, the matching admin actions can only happen through an
+/// approved `Proposal`, either as a dedicated action or by invoking the admin
+/// instruction with the multisig PDA as signer.
+#[account]
+pub struct Multisig {
+    /// Casino this multisig administers
+    pub casino: Pubkey,
+    /// Keys allowed to create and approve proposals
+    pub signers: Vec<Pubkey>,
+    /// Approvals needed to execute a proposal
+    pub threshold: u8,
+    /// Bumped whenever the signer set changes, invalidating open proposals
+    pub version: u32,
+    /// Number of proposals created, used to derive proposal addresses
+    pub proposal_count: u64,
+    /// Timestamp when multisig was created
+    pub created_at: i64,
+    /// Last update timestamp
+    pub updated_at: i64,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl Multisig {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // casino
+        4 + 32 * MAX_MULTISIG_SIGNERS + // signers
+        1 + // threshold
+        4 + // version
+        8 + // proposal_count
+        8 + // created_at
+        8 + // updated_at
+        1 + // bump
+        64; // padding for future fields
+
+    pub fn validate_signers(signers: &[Pubkey], threshold: u8) -> Result<()> {
+        require!(
+            !signers.is_empty() && signers.len() <= MAX_MULTISIG_SIGNERS,
+            crate::errors::CasinoError::InvalidConfiguration
+        );
+        require!(
+            threshold > 0 && threshold as usize <= signers.len(),
+            crate::errors::CasinoError::InvalidConfiguration
+        );
+        for (i, signer) in signers.iter().enumerate() {
+            require!(!signers[..i].contains(signer), crate::errors::CasinoError::InvalidConfiguration);
+        }
+        Ok(())
+    }
+
+    pub fn signer_index(&self, key: &Pubkey) -> Result<usize> {
+        self.signers
+            .iter()
+            .position(|signer| signer == key)
+            .ok_or(crate::errors::CasinoError::InsufficientPermissions.into())
+    }
+
+    /// Commits to the accounts of an invoked instruction, in order and with their
+    /// signer and writable flags
+    pub fn accounts_hash(accounts: &[AccountMeta]) -> [u8; 32] {
+        let mut hasher = Sha256::new();
+        for account in accounts {
+            hasher.update(account.pubkey.as_ref());
+            hasher.update([account.is_signer as u8, account.is_writable as u8]);
+        }
+        hasher.finalize().into()
+    }
+
+    pub fn set_signers(&mut self, signers: Vec<Pubkey>, threshold: u8, now: i64) -> Result<()> {
+        Self::validate_signers(&signers, threshold)?;
+        self.signers = signers;
+        self.threshold = threshold;
+        self.version = self.version.wrapping_add(1);
+        self.updated_at = now;
+        Ok(())
+    }
+}
+
+/// Admin action awaiting approval by the multisig signers
+#[account]
+pub struct Proposal {
+    /// Multisig the proposal belongs to
+    pub multisig: Pubkey,
+    /// Sequential id within the multisig
+    pub proposal_id: u64,
+    /// Signer that created the proposal and receives its rent back
+    pub proposer: Pubkey,
+    /// Action carried out on execution
+    pub action: ProposalAction,
+    /// Multisig version the approvals were collected under
+    pub version: u32,
+    /// Bitmask of approving signers, by index in `Multisig.signers`
+    pub approvals: u16,
+    /// Current status
+    pub status: ProposalStatus,
+    /// Timestamp when proposal was created
+    pub created_at: i64,
+    /// Deadline after which the proposal can no longer execute
+    pub expires_at: i64,
+    /// Timestamp of execution
+    pub executed_at: Option<i64>,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl Proposal {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // multisig
+        8 + // proposal_id
+        32 + // proposer
+        ProposalAction::LEN + // action
+        4 + // version
+        2 + // approvals
+        1 + // status
+        8 + // created_at
+        8 + // expires_at
+        1 + 8 + // executed_at (optional)
+        1 + // bump
+        32; // padding for future fields
+
+    pub fn approval_count(&self) -> u8 {
+        self.approvals.count_ones() as u8
+    }
+
+    pub fn is_open(&self, multisig: &Multisig, now: i64) -> bool {
+        self.status == ProposalStatus::Active && self.version == multisig.version && now < self.expires_at
+    }
+
+    /// Open proposals accept approvals and execute until they expire or the
+    /// signer set changes
+    pub fn validate_open(&self, multisig: &Multisig, now: i64) -> Result<()> {
+        require!(self.status == ProposalStatus::Active, crate::errors::CasinoError::InvalidStateTransition);
+        require!(self.version == multisig.version, crate::errors::CasinoError::InvalidStateTransition);
+        require!(now < self.expires_at, crate::errors::CasinoError::ProposalExpired);
+        Ok(())
+    }
+
+    pub fn approve(&mut self, signer_index: usize) -> Result<()> {
+        let bit = 1u16 << signer_index;
+        require!(self.approvals & bit == 0, crate::errors::CasinoError::InvalidStateTransition);
+        self.approvals |= bit;
+        Ok(())
+    }
+
+    /// Withdraws an open proposal; it can then be closed for its rent
+    pub fn cancel(&mut self, multisig: &Multisig, now: i64) -> Result<()> {
+        self.validate_open(multisig, now)?;
+        self.status = ProposalStatus::Cancelled;
+        Ok(())
+    }
+
+    pub fn validate_executable(&self, multisig: &Multisig, now: i64) -> Result<()> {
+        self.validate_open(multisig, now)?;
+        require!(
+            self.approval_count() >= multisig.threshold,
+            crate::errors::CasinoError::ProposalThresholdNotMet
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn multisig(signers: usize, threshold: u8) -> Multisig {
+        Multisig {
+            casino: Pubkey::new_unique(),
+            signers: (0..signers).map(|_| Pubkey::new_unique()).collect(),
+            threshold,
+            version: 0,
+            proposal_count: 0,
+            created_at: 0,
+            updated_at: 0,
+            bump: 255,
+        }
+    }
+
+    fn proposal(multisig: &Multisig) -> Proposal {
+        Proposal {
+            multisig: Pubkey::new_unique(),
+            proposal_id: 0,
+            proposer: multisig.signers[0],
+            action: ProposalAction::SetSigners { signers: multisig.signers.clone(), threshold: 1 },
+            version: multisig.version,
+            approvals: 0,
+            status: ProposalStatus::Active,
+            created_at: 0,
+            expires_at: 100,
+            executed_at: None,
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn approvals_set_one_bit_per_signer() {
+        let multisig = multisig(MAX_MULTISIG_SIGNERS, 3);
+        let mut proposal = proposal(&multisig);
+
+        proposal.approve(0).unwrap();
+        proposal.approve(MAX_MULTISIG_SIGNERS - 1).unwrap();
+        assert_eq!(proposal.approvals, 1 | 1 << (MAX_MULTISIG_SIGNERS - 1));
+        assert_eq!(proposal.approval_count(), 2);
+    }
+
+    #[test]
+    fn signer_cannot_approve_twice() {
+        let multisig = multisig(3, 2);
+        let mut proposal = proposal(&multisig);
+
+        proposal.approve(1).unwrap();
+        assert!(proposal.approve(1).is_err());
+        assert_eq!(proposal.approval_count(), 1);
+    }
+
+    #[test]
+    fn executes_only_at_threshold() {
+        let multisig = multisig(3, 2);
+        let mut proposal = proposal(&multisig);
+
+        proposal.approve(0).unwrap();
+        assert!(proposal.validate_executable(&multisig, 0).is_err());
+        proposal.approve(2).unwrap();
+        assert!(proposal.validate_executable(&multisig, 0).is_ok());
+        assert!(proposal.validate_executable(&multisig, 100).is_err());
+    }
+
+    #[test]
+    fn signer_change_invalidates_approvals() {
+        let mut multisig = multisig(3, 1);
+        let mut proposal = proposal(&multisig);
+        proposal.approve(0).unwrap();
+
+        let signers = multisig.signers.clone();
+        multisig.set_signers(signers, 1, 0).unwrap();
+        assert!(!proposal.is_open(&multisig, 0));
+        assert!(proposal.validate_executable(&multisig, 0).is_err());
+    }
+
+    #[test]
+    fn cancelled_proposal_cannot_execute() {
+        let multisig = multisig(1, 1);
+        let mut proposal = proposal(&multisig);
+        proposal.approve(0).unwrap();
+
+        proposal.cancel(&multisig, 0).unwrap();
+        assert!(!proposal.is_open(&multisig, 0));
+        assert!(proposal.validate_executable(&multisig, 0).is_err());
+        assert!(proposal.cancel(&multisig, 0).is_err());
+    }
+
+    #[test]
+    fn accounts_hash_covers_order_and_flags() {
+        let (a, b) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let hash = Multisig::accounts_hash(&[AccountMeta::new(a, true), AccountMeta::new_readonly(b, false)]);
+
+        assert_eq!(hash, Multisig::accounts_hash(&[AccountMeta::new(a, true), AccountMeta::new_readonly(b, false)]));
+        assert_ne!(hash, Multisig::accounts_hash(&[AccountMeta::new_readonly(b, false), AccountMeta::new(a, true)]));
+        assert_ne!(hash, Multisig::accounts_hash(&[AccountMeta::new(a, false), AccountMeta::new_readonly(b, false)]));
+        assert_ne!(hash, Multisig::accounts_hash(&[AccountMeta::new(a, true), AccountMeta::new(b, false)]));
+    }
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+/// Compliance ban keeping a player from placing new bets at a casino.
+///
+/// The ban is the account's existence: bet instructions require the player's ban
+/// PDA to be empty, and lifting the ban closes it. Bets already placed can still be
+/// resolved, claimed or refunded.
+#[account]
+pub struct PlayerBan {
+    /// Casino the ban applies to
+    pub casino: Pubkey,
+    /// Banned player
+    pub player: Pubkey,
+    /// Compliance key that issued the ban
+    pub banned_by: Pubkey,
+    /// Timestamp when the ban was issued
+    pub banned_at: i64,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl PlayerBan {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // casino
+        32 + // player
+        32 + // banned_by
+        8 + // banned_at
+        1 + // bump
+        32; // padding for future fields
+
+    /// Fails if `ban` (the player's ban PDA, checked by seeds) has been created
+    pub fn require_not_banned(ban: &AccountInfo) -> Result<()> {
+        require!(ban.data_is_empty(), crate::errors::CasinoError::AccountBanned);
+        Ok(())
+    }
+}
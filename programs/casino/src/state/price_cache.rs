@@ -0,0 +1,230 @@
+use anchor_lang::prelude::*;
+use super::{PoolKind, PriceObservation, MAX_TWAP_WINDOW, MIN_TWAP_WINDOW, TWAP_OBSERVATIONS};
+use crate::oracle::PRICE_DECIMALS;
+
+/// Time-weighted average price of a token, cranked from a constant-product pool.
+///
+/// Prices are USD per whole token with `PRICE_DECIMALS` decimals; the pool's quote
+/// token is assumed to be a USD stablecoin.
+#[account]
+pub struct PriceCache {
+    /// Casino that created this cache
+    pub casino: Pubkey,
+    /// Token being priced (pool base token)
+    pub mint: Pubkey,
+    /// USD stablecoin the pool quotes against
+    pub quote_mint: Pubkey,
+    /// How reserves are read from the pool
+    pub pool_kind: PoolKind,
+    /// Pool account holding the base reserve (the mock pool itself for `PoolKind::Mock`)
+    pub base_reserve: Pubkey,
+    /// Pool account holding the quote reserve
+    pub quote_reserve: Pubkey,
+    /// Decimals of the base mint
+    pub base_decimals: u8,
+    /// Decimals of the quote mint
+    pub quote_decimals: u8,
+    /// TWAP window in seconds
+    pub window: i64,
+    /// Spot price at the last crank
+    pub spot_price: u128,
+    /// Spot price integrated over time since the first crank
+    pub cumulative_price: u128,
+    /// Timestamp of the last crank
+    pub last_updated: i64,
+    /// Ring buffer of cumulative price snapshots spanning roughly one window
+    pub observations: [PriceObservation; TWAP_OBSERVATIONS],
+    /// Index of the most recent observation
+    pub observation_index: u8,
+    /// Number of observations recorded, up to `TWAP_OBSERVATIONS`
+    pub observation_count: u8,
+    /// Timestamp when cache was created
+    pub created_at: i64,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl PriceCache {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // casino
+        32 + // mint
+        32 + // quote_mint
+        1 + // pool_kind
+        32 + // base_reserve
+        32 + // quote_reserve
+        1 + // base_decimals
+        1 + // quote_decimals
+        8 + // window
+        16 + // spot_price
+        16 + // cumulative_price
+        8 + // last_updated
+        (8 + 16) * TWAP_OBSERVATIONS + // observations
+        1 + // observation_index
+        1 + // observation_count
+        8 + // created_at
+        1 + // bump
+        64; // padding for future fields
+
+    pub fn validate_config(&self) -> Result<()> {
+        require!(
+            self.window >= MIN_TWAP_WINDOW && self.window <= MAX_TWAP_WINDOW,
+            crate::errors::CasinoError::InvalidConfiguration
+        );
+        Ok(())
+    }
+
+    /// USD price of one whole base token implied by the pool reserves
+    pub fn spot_price(&self, base_reserve: u64, quote_reserve: u64) -> Result<u128> {
+        require!(
+            base_reserve > 0 && quote_reserve > 0,
+            crate::errors::CasinoError::OraclePriceFeedInvalid
+        );
+
+        let base_scale = 10u128
+            .checked_pow(self.base_decimals as u32 + PRICE_DECIMALS)
+            .ok_or(crate::errors::CasinoError::ArithmeticOverflow)?;
+        let quote_scale = 10u128
+            .checked_pow(self.quote_decimals as u32)
+            .ok_or(crate::errors::CasinoError::ArithmeticOverflow)?;
+
+        let numerator = (quote_reserve as u128)
+            .checked_mul(base_scale)
+            .ok_or(crate::errors::CasinoError::ArithmeticOverflow)?;
+        Ok(numerator / (base_reserve as u128 * quote_scale))
+    }
+
+    /// Accumulates the previous spot price up to `now` and records the new one
+    pub fn record(&mut self, spot_price: u128, now: i64) -> Result<()> {
+        self.cumulative_price = self.cumulative_at(now)?;
+        self.spot_price = spot_price;
+        self.last_updated = now;
+
+        let latest = self.observations[self.observation_index as usize];
+        let interval = self.window / TWAP_OBSERVATIONS as i64;
+        if self.observation_count == 0 || now - latest.timestamp >= interval {
+            if self.observation_count > 0 {
+                self.observation_index = ((self.observation_index as usize + 1) % TWAP_OBSERVATIONS) as u8;
+            }
+            self.observations[self.observation_index as usize] = PriceObservation {
+                timestamp: now,
+                cumulative_price: self.cumulative_price,
+            };
+            self.observation_count = (self.observation_count + 1).min(TWAP_OBSERVATIONS as u8);
+        }
+        Ok(())
+    }
+
+    /// Average price over the oldest observation still inside the window
+    pub fn twap(&self, now: i64) -> Result<u128> {
+        let oldest = self.observations[..self.observation_count as usize]
+            .iter()
+            .filter(|observation| observation.timestamp >= now - self.window)
+            .min_by_key(|observation| observation.timestamp)
+            .ok_or(crate::errors::CasinoError::OraclePriceFeedStale)?;
+
+        // Too little history to average over, e.g. right after creation
+        let span = now - oldest.timestamp;
+        require!(span >= self.window / 2, crate::errors::CasinoError::OraclePriceFeedInvalid);
+
+        let cumulative = self.cumulative_at(now)?;
+        Ok((cumulative - oldest.cumulative_price) / span as u128)
+    }
+
+    fn cumulative_at(&self, now: i64) -> Result<u128> {
+        if self.last_updated == 0 {
+            return Ok(self.cumulative_price);
+        }
+        let elapsed = now.saturating_sub(self.last_updated).max(0) as u128;
+        self.spot_price
+            .checked_mul(elapsed)
+            .and_then(|accrued| self.cumulative_price.checked_add(accrued))
+            .ok_or(crate::errors::CasinoError::ArithmeticOverflow.into())
+    }
+}
+
+/// Reserve layout of a mock pool account injected by local tests
+#[cfg(feature = "mock-pool")]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct MockPool {
+    pub base_reserve: u64,
+    pub quote_reserve: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ONE: u128 = 1_000_000_000_000; // $1 in PRICE_DECIMALS
+
+    fn cache(window: i64) -> PriceCache {
+        PriceCache {
+            casino: Pubkey::new_unique(),
+            mint: Pubkey::new_unique(),
+            quote_mint: Pubkey::new_unique(),
+            pool_kind: PoolKind::Mock,
+            base_reserve: Pubkey::new_unique(),
+            quote_reserve: Pubkey::new_unique(),
+            base_decimals: 6,
+            quote_decimals: 6,
+            window,
+            spot_price: 0,
+            cumulative_price: 0,
+            last_updated: 0,
+            observations: [PriceObservation::default(); TWAP_OBSERVATIONS],
+            observation_index: 0,
+            observation_count: 0,
+            created_at: 0,
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn spot_price_follows_reserve_ratio() {
+        let cache = cache(600);
+        assert_eq!(cache.spot_price(1_000_000_000, 2_000_000_000).unwrap(), 2 * ONE);
+        assert_eq!(cache.spot_price(4_000_000, 1_000_000).unwrap(), ONE / 4);
+        assert!(cache.spot_price(0, 1_000_000).is_err());
+    }
+
+    #[test]
+    fn twap_weights_prices_by_time_held() {
+        let mut cache = cache(600);
+        cache.record(ONE, 1_000).unwrap();
+        cache.record(3 * ONE, 1_300).unwrap();
+
+        // $1 for 300s then $3 for 300s
+        assert_eq!(cache.twap(1_600).unwrap(), 2 * ONE);
+        assert_eq!(cache.observation_count, 2);
+    }
+
+    #[test]
+    fn frequent_cranks_accumulate_without_new_observations() {
+        let mut cache = cache(800);
+        cache.record(ONE, 1_000).unwrap();
+        cache.record(ONE, 1_010).unwrap();
+        cache.record(2 * ONE, 1_020).unwrap();
+
+        assert_eq!(cache.observation_count, 1);
+        assert_eq!(cache.cumulative_price, 20 * ONE);
+        // $1 for 20s then $2 for 380s
+        assert_eq!(cache.twap(1_400).unwrap(), (20 * ONE + 380 * 2 * ONE) / 400);
+    }
+
+    #[test]
+    fn twap_needs_half_a_window_of_history() {
+        let mut cache = cache(600);
+        cache.record(ONE, 1_000).unwrap();
+
+        assert!(cache.twap(1_299).is_err());
+        assert_eq!(cache.twap(1_300).unwrap(), ONE);
+    }
+
+    #[test]
+    fn twap_without_recent_observations_is_stale() {
+        let mut cache = cache(600);
+        assert!(cache.twap(1_000).is_err());
+
+        cache.record(ONE, 1_000).unwrap();
+        assert!(cache.twap(1_601).is_err());
+    }
+}
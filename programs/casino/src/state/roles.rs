@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+use super::{Role, ROLE_COUNT};
+
+/// Keys allowed to perform each class of admin action on a casino.
+///
+/// `Casino.authority` owns the casino and assigns the roles, but holds none of them
+/// unless assigned. Every role starts out held by the authority, and moves to a
+/// new key only once that key accepts it.
+#[account]
+pub struct CasinoRoles {
+    /// Casino these roles govern
+    pub casino: Pubkey,
+    /// Changes configuration, limits and supported mints
+    pub operator: Pubkey,
+    /// Commits and reveals server seeds
+    pub resolver: Pubkey,
+    /// Withdraws treasury fees
+    pub treasurer: Pubkey,
+    /// Triggers emergency pauses, but cannot unpause
+    pub pauser: Pubkey,
+    /// Bans and unbans players. KYC is not checked on-chain; operators needing it gate
+    /// players before they reach the casino
+    pub compliance: Pubkey,
+    /// Keys proposed for each role, indexed by `Role`, awaiting their acceptance
+    pub pending: [Option<Pubkey>; ROLE_COUNT],
+    /// Timestamp when roles were created
+    pub created_at: i64,
+    /// Last update timestamp
+    pub updated_at: i64,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl CasinoRoles {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // casino
+        32 + // operator
+        32 + // resolver
+        32 + // treasurer
+        32 + // pauser
+        32 + // compliance
+        (1 + 32) * ROLE_COUNT + // pending
+        8 + // created_at
+        8 + // updated_at
+        1 + // bump
+        64; // padding for future fields
+
+    pub fn holder(&self, role: Role) -> Pubkey {
+        match role {
+            Role::Operator => self.operator,
+            Role::Resolver => self.resolver,
+            Role::Treasurer => self.treasurer,
+            Role::Pauser => self.pauser,
+            Role::Compliance => self.compliance,
+        }
+    }
+
+    pub fn has_role(&self, role: Role, key: &Pubkey) -> bool {
+        self.holder(role) == *key
+    }
+
+    pub fn assign(&mut self, role: Role, holder: Pubkey) {
+        match role {
+            Role::Operator => self.operator = holder,
+            Role::Resolver => self.resolver = holder,
+            Role::Treasurer => self.treasurer = holder,
+            Role::Pauser => self.pauser = holder,
+            Role::Compliance => self.compliance = holder,
+        }
+    }
+
+    pub fn pending_holder(&self, role: Role) -> Option<Pubkey> {
+        self.pending[role as usize]
+    }
+
+    pub fn set_pending(&mut self, role: Role, proposed: Option<Pubkey>) {
+        self.pending[role as usize] = proposed;
+    }
+
+    /// Proposes a new holder for `role`, replacing any pending proposal. Returns the
+    /// current holder.
+    pub fn propose(&mut self, role: Role, proposed: Pubkey, now: i64) -> Result<Pubkey> {
+        require_keys_neq!(proposed, Pubkey::default(), crate::errors::CasinoError::InvalidConfiguration);
+        let current = self.holder(role);
+        require_keys_neq!(proposed, current, crate::errors::CasinoError::InvalidConfiguration);
+
+        self.set_pending(role, Some(proposed));
+        self.updated_at = now;
+        Ok(current)
+    }
+
+    /// Hands `role` to `holder` if it was proposed to it. Returns the previous holder.
+    pub fn accept(&mut self, role: Role, holder: Pubkey, now: i64) -> Result<Pubkey> {
+        require!(self.pending_holder(role) == Some(holder), crate::errors::CasinoError::Unauthorized);
+        let previous = self.holder(role);
+        self.assign(role, holder);
+        self.set_pending(role, None);
+        self.updated_at = now;
+        Ok(previous)
+    }
+
+    /// Drops the pending proposal for `role` and returns who it was for
+    pub fn cancel_transfer(&mut self, role: Role, now: i64) -> Option<Pubkey> {
+        let proposed = self.pending_holder(role);
+        self.set_pending(role, None);
+        self.updated_at = now;
+        proposed
+    }
+}
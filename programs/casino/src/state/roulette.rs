@@ -0,0 +1,359 @@
+use anchor_lang::prelude::*;
+use super::{RouletteBetKind, RouletteRoundStatus, BASIS_POINTS, ROULETTE_POCKETS, ROULETTE_SPIN_DEADLINE};
+
+const RED_NUMBERS: [u8; 18] = [1, 3, 5, 7, 9, 12, 14, 16, 18, 19, 21, 23, 25, 27, 30, 32, 34, 36];
+
+/// Multi-player roulette round banked by the casino.
+///
+/// The spin mixes the committed server seed with the hash of `entropy_slot`, a slot
+/// after betting closes, so nobody who knows the seed can steer the result with a
+/// last-moment bet. If the round is not spun in time, every bet can be refunded with
+/// its share of the round's worst case, so the operator never gains by withholding a
+/// spin once the entropy slot has revealed the result.
+#[account]
+pub struct RouletteRound {
+    /// Casino banking the round
+    pub casino: Pubkey,
+    /// Operator-chosen round identifier, part of the PDA seeds
+    pub round_id: u64,
+    /// SHA-256 hash of the server seed committed when the round opened
+    pub server_seed_hash: String,
+    /// Server seed (revealed at spin)
+    pub server_seed: Option<String>,
+    /// Running hash of every bet placed, mixed into the spin
+    pub bet_entropy: [u8; 32],
+    /// Timestamp after which no more bets are accepted
+    pub closes_at: i64,
+    /// Slot whose hash is mixed into the spin; no bets are accepted from this slot on
+    pub entropy_slot: u64,
+    /// Hash of `entropy_slot` (populated at the spin)
+    pub slot_hash: Option<[u8; 32]>,
+    /// Current round status
+    pub status: RouletteRoundStatus,
+    /// Winning pocket (populated after the spin)
+    pub result: Option<u8>,
+    /// Number of bets placed, used to derive bet receipt PDAs
+    pub bet_count: u64,
+    /// Total amount staked in the round
+    pub total_staked: u64,
+    /// Total payout owed for each pocket if it wins
+    pub payouts_by_pocket: [u64; ROULETTE_POCKETS],
+    /// Liability currently reserved against the vault for this round
+    pub reserved_liability: u64,
+    /// Timestamp when round was opened
+    pub created_at: i64,
+    /// Timestamp when round was spun
+    pub spun_at: Option<i64>,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl RouletteRound {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // casino
+        8 + // round_id
+        4 + 64 + // server_seed_hash
+        1 + 4 + 64 + // server_seed (optional)
+        32 + // bet_entropy
+        8 + // closes_at
+        8 + // entropy_slot
+        1 + 32 + // slot_hash (optional)
+        1 + // status
+        1 + 1 + // result (optional)
+        8 + // bet_count
+        8 + // total_staked
+        8 * ROULETTE_POCKETS + // payouts_by_pocket
+        8 + // reserved_liability
+        8 + // created_at
+        1 + 8 + // spun_at (optional)
+        1 + // bump
+        64; // padding for future fields
+
+    pub fn is_betting_open(&self, now: i64, slot: u64) -> bool {
+        matches!(self.status, RouletteRoundStatus::Open) && now < self.closes_at && slot < self.entropy_slot
+    }
+
+    /// Bets in a round the operator failed to spin can be refunded by anyone
+    pub fn is_refundable(&self, now: i64) -> bool {
+        match self.status {
+            RouletteRoundStatus::Open => now >= self.closes_at + ROULETTE_SPIN_DEADLINE,
+            RouletteRoundStatus::Refunded => true,
+            RouletteRoundStatus::Spun => false,
+        }
+    }
+
+    /// Total paid back by a round that is not spun in time: the most any pocket would
+    /// have paid, and never less than the stakes
+    pub fn refund_total(&self) -> u64 {
+        self.max_liability().max(self.total_staked)
+    }
+
+    /// A bet's share of `refund_total`, in proportion to its stake
+    pub fn refund_for(&self, amount: u64) -> Result<u64> {
+        if self.total_staked == 0 {
+            return Ok(0);
+        }
+        let refund = (amount as u128 * self.refund_total() as u128) / self.total_staked as u128;
+        u64::try_from(refund).map_err(|_| crate::errors::CasinoError::ArithmeticOverflow.into())
+    }
+
+    /// Highest total payout across all pockets
+    pub fn max_liability(&self) -> u64 {
+        self.payouts_by_pocket.iter().copied().max().unwrap_or(0)
+    }
+
+    /// Adds a bet to the round and returns the extra liability it requires
+    pub fn add_bet(&mut self, player: &Pubkey, kind: RouletteBetKind, selection: u8, amount: u64, payout: u64) -> Result<u64> {
+        use sha2::{Sha256, Digest};
+
+        let previous_liability = self.max_liability();
+        for pocket in 0..ROULETTE_POCKETS as u8 {
+            if kind.covers(selection, pocket) {
+                let slot = &mut self.payouts_by_pocket[pocket as usize];
+                *slot = slot.checked_add(payout).ok_or(crate::errors::CasinoError::ArithmeticOverflow)?;
+            }
+        }
+
+        let mut hasher = Sha256::new();
+        hasher.update(self.bet_entropy);
+        hasher.update(player.as_ref());
+        hasher.update(amount.to_le_bytes());
+        hasher.update(self.bet_count.to_le_bytes());
+        self.bet_entropy = hasher.finalize().into();
+
+        self.bet_count = self.bet_count.saturating_add(1);
+        self.total_staked = self.total_staked
+            .checked_add(amount)
+            .ok_or(crate::errors::CasinoError::ArithmeticOverflow)?;
+
+        Ok(self.max_liability().saturating_sub(previous_liability))
+    }
+
+    pub fn verify_server_seed(&self, server_seed: &str) -> bool {
+        super::hash_seed(server_seed) == self.server_seed_hash
+    }
+
+    /// Derives the winning pocket from the revealed server seed, the bet entropy and
+    /// the hash of the entropy slot
+    pub fn spin(&self, server_seed: &str, slot_hash: &[u8; 32]) -> u8 {
+        use sha2::{Sha256, Digest};
+
+        let combined_seed = format!(
+            "{}-{}-{}-{}",
+            server_seed,
+            hex::encode(self.bet_entropy),
+            hex::encode(slot_hash),
+            self.round_id
+        );
+
+        let mut hasher = Sha256::new();
+        hasher.update(combined_seed.as_bytes());
+        let hash = hasher.finalize();
+
+        let draw = u64::from_le_bytes(hash[0..8].try_into().unwrap());
+        (draw % ROULETTE_POCKETS as u64) as u8
+    }
+}
+
+/// Hash of `slot`, or of the first produced slot after it when it was skipped,
+/// from raw SlotHashes sysvar data (entries newest first). None until the slot has
+/// passed, or once history no longer reaches back to it.
+pub fn find_slot_hash(data: &[u8], slot: u64) -> Option<[u8; 32]> {
+    const ENTRY_LEN: usize = 8 + 32;
+
+    let len = u64::from_le_bytes(data.get(0..8)?.try_into().ok()?) as usize;
+    let mut later = None;
+    for index in 0..len {
+        let entry = data.get(8 + index * ENTRY_LEN..8 + (index + 1) * ENTRY_LEN)?;
+        let entry_slot = u64::from_le_bytes(entry[0..8].try_into().ok()?);
+        let hash: [u8; 32] = entry[8..].try_into().ok()?;
+        if entry_slot == slot {
+            return Some(hash);
+        }
+        if entry_slot < slot {
+            return later;
+        }
+        later = Some(hash);
+    }
+    None
+}
+
+#[account]
+pub struct RouletteBet {
+    /// Round the bet was placed in
+    pub round: Pubkey,
+    /// Player who placed the bet
+    pub player: Pubkey,
+    /// Position of the bet within the round
+    pub bet_index: u64,
+    /// Kind of bet
+    pub kind: RouletteBetKind,
+    /// Pocket, dozen or column selected (depending on kind)
+    pub selection: u8,
+    /// Bet amount in token units
+    pub amount: u64,
+    /// Amount paid out when claimed
+    pub payout: u64,
+    /// Whether winnings have been claimed
+    pub claimed: bool,
+    /// Timestamp when bet was placed
+    pub created_at: i64,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl RouletteBet {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // round
+        32 + // player
+        8 + // bet_index
+        1 + // kind
+        1 + // selection
+        8 + // amount
+        8 + // payout
+        1 + // claimed
+        8 + // created_at
+        1 + // bump
+        32; // padding for future fields
+
+    pub fn potential_payout(&self) -> Result<u64> {
+        self.kind.payout(self.selection, self.amount)
+    }
+
+    pub fn winnings(&self, result: u8) -> Result<u64> {
+        if self.kind.covers(self.selection, result) {
+            self.potential_payout()
+        } else {
+            Ok(0)
+        }
+    }
+}
+
+impl RouletteBetKind {
+    pub fn validate_selection(&self, selection: u8) -> Result<()> {
+        let valid = match self {
+            RouletteBetKind::Straight => (selection as usize) < ROULETTE_POCKETS,
+            RouletteBetKind::Dozen | RouletteBetKind::Column => selection < 3,
+            _ => selection == 0,
+        };
+        require!(valid, crate::errors::CasinoError::InvalidPrediction);
+        Ok(())
+    }
+
+    pub fn multiplier(&self) -> u64 {
+        match self {
+            RouletteBetKind::Straight => super::ROULETTE_STRAIGHT_PAYOUT,
+            RouletteBetKind::Dozen | RouletteBetKind::Column => super::ROULETTE_DOZEN_PAYOUT,
+            _ => super::ROULETTE_EVEN_MONEY_PAYOUT,
+        }
+    }
+
+    pub fn payout(&self, selection: u8, amount: u64) -> Result<u64> {
+        self.validate_selection(selection)?;
+
+        let payout = (amount as u128 * self.multiplier() as u128) / BASIS_POINTS as u128;
+        u64::try_from(payout).map_err(|_| crate::errors::CasinoError::ArithmeticOverflow.into())
+    }
+
+    pub fn covers(&self, selection: u8, pocket: u8) -> bool {
+        if pocket == 0 {
+            return matches!(self, RouletteBetKind::Straight) && selection == 0;
+        }
+
+        match self {
+            RouletteBetKind::Straight => selection == pocket,
+            RouletteBetKind::Red => RED_NUMBERS.contains(&pocket),
+            RouletteBetKind::Black => !RED_NUMBERS.contains(&pocket),
+            RouletteBetKind::Odd => pocket % 2 == 1,
+            RouletteBetKind::Even => pocket % 2 == 0,
+            RouletteBetKind::Low => pocket <= 18,
+            RouletteBetKind::High => pocket >= 19,
+            RouletteBetKind::Dozen => (pocket - 1) / 12 == selection,
+            RouletteBetKind::Column => (pocket - 1) % 3 == selection,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// SlotHashes data holding `slots` newest first, each hashed to its own number
+    fn slot_hashes(slots: &[u64]) -> Vec<u8> {
+        let mut data = (slots.len() as u64).to_le_bytes().to_vec();
+        for slot in slots {
+            data.extend_from_slice(&slot.to_le_bytes());
+            data.extend_from_slice(&[*slot as u8; 32]);
+        }
+        data
+    }
+
+    #[test]
+    fn slot_hash_of_produced_slot() {
+        let data = slot_hashes(&[105, 104, 103, 102]);
+        assert_eq!(find_slot_hash(&data, 103), Some([103; 32]));
+        assert_eq!(find_slot_hash(&data, 102), Some([102; 32]));
+    }
+
+    #[test]
+    fn skipped_slot_uses_next_produced_slot() {
+        let data = slot_hashes(&[106, 105, 102, 101]);
+        assert_eq!(find_slot_hash(&data, 103), Some([105; 32]));
+    }
+
+    #[test]
+    fn slot_hash_unavailable_before_slot_and_after_history() {
+        let data = slot_hashes(&[105, 104, 103]);
+        assert_eq!(find_slot_hash(&data, 106), None);
+        assert_eq!(find_slot_hash(&data, 101), None);
+    }
+
+    fn round() -> RouletteRound {
+        RouletteRound {
+            casino: Pubkey::new_unique(),
+            round_id: 0,
+            server_seed_hash: String::new(),
+            server_seed: None,
+            bet_entropy: [0; 32],
+            closes_at: 0,
+            entropy_slot: 0,
+            slot_hash: None,
+            status: RouletteRoundStatus::Open,
+            result: None,
+            bet_count: 0,
+            total_staked: 0,
+            payouts_by_pocket: [0; ROULETTE_POCKETS],
+            reserved_liability: 0,
+            created_at: 0,
+            spun_at: None,
+            bump: 0,
+        }
+    }
+
+    fn place(round: &mut RouletteRound, kind: RouletteBetKind, selection: u8, amount: u64) {
+        let payout = kind.payout(selection, amount).unwrap();
+        round.add_bet(&Pubkey::new_unique(), kind, selection, amount, payout).unwrap();
+    }
+
+    #[test]
+    fn unspun_round_refunds_its_worst_case_pro_rata() {
+        let mut round = round();
+        place(&mut round, RouletteBetKind::Straight, 7, 100);
+        place(&mut round, RouletteBetKind::Red, 0, 300);
+
+        // Pocket 7 is red and would have paid 3,500 + 600
+        assert_eq!(round.refund_total(), 4_100);
+        assert_eq!(round.refund_for(100).unwrap(), 1_025);
+        assert_eq!(round.refund_for(300).unwrap(), 3_075);
+    }
+
+    #[test]
+    fn unspun_round_refunds_at_least_the_stakes() {
+        let mut round = round();
+        place(&mut round, RouletteBetKind::Red, 0, 100);
+        place(&mut round, RouletteBetKind::Black, 0, 100);
+
+        assert_eq!(round.refund_total(), 200);
+        assert_eq!(round.refund_for(100).unwrap(), 100);
+    }
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+/// Server seed hash committed by the casino before any player can bet against it.
+///
+/// The PDA is derived from the hash itself and is never closed, so each hash can be
+/// committed once and bound to a single game: a player cannot replay the hash of a
+/// seed that has already been revealed, and the casino cannot re-issue one.
+#[account]
+pub struct SeedCommitment {
+    /// Casino that committed the hash
+    pub casino: Pubkey,
+    /// SHA-256 of the server seed
+    pub hash: [u8; 32],
+    /// Key that committed the hash
+    pub committed_by: Pubkey,
+    /// Timestamp when the hash was committed
+    pub committed_at: i64,
+    /// Game the hash was bound to, once a player has bet against it
+    pub game: Option<Pubkey>,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl SeedCommitment {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // casino
+        32 + // hash
+        32 + // committed_by
+        8 + // committed_at
+        1 + 32 + // game (optional)
+        1 + // bump
+        32; // padding for future fields
+
+    /// Binds the hash to `game`; a hash can back exactly one game
+    pub fn consume(&mut self, game: Pubkey) -> Result<String> {
+        require!(self.game.is_none(), crate::errors::CasinoError::ServerSeedAlreadyUsed);
+        self.game = Some(game);
+        Ok(hex::encode(self.hash))
+    }
+}
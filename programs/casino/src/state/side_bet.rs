@@ -0,0 +1,97 @@
+use anchor_lang::prelude::*;
+use super::{Coup, GameType, SideBet, SideBetKind, BASIS_POINTS, BACCARAT_PAIR_PAYOUT};
+
+// Dice side bet paytable. A roll maps one outcome byte onto 1-100, so rolls 1-56
+// come up three times in 256 and rolls 57-100 twice; each pays to the same edge.
+pub const LUCKY_NUMBER_LOW_PAYOUT: u64 = 810000; // 81x for hitting an exact roll of 1-56
+pub const LUCKY_NUMBER_HIGH_PAYOUT: u64 = 1210000; // 121x for hitting an exact roll of 57-100
+
+impl SideBetKind {
+    pub fn is_allowed_for(&self, game_type: GameType) -> bool {
+        match self {
+            SideBetKind::PlayerPair | SideBetKind::BankerPair => game_type == GameType::Baccarat,
+            SideBetKind::LuckyNumber => game_type == GameType::DiceRoll,
+        }
+    }
+
+    pub fn max_multiplier(&self, selection: u8) -> u64 {
+        match self {
+            SideBetKind::PlayerPair | SideBetKind::BankerPair => BACCARAT_PAIR_PAYOUT,
+            SideBetKind::LuckyNumber if selection <= 56 => LUCKY_NUMBER_LOW_PAYOUT,
+            SideBetKind::LuckyNumber => LUCKY_NUMBER_HIGH_PAYOUT,
+        }
+    }
+
+    pub fn validate_selection(&self, selection: u8) -> Result<()> {
+        let valid = match self {
+            SideBetKind::LuckyNumber => (1..=100).contains(&selection),
+            _ => selection == 0,
+        };
+        require!(valid, crate::errors::CasinoError::InvalidSideBet);
+        Ok(())
+    }
+
+    /// Evaluates the side bet against the main game outcome and returns its multiplier.
+    ///
+    /// Pair bets look at the first two cards of each hand in the coup dealt from `outcome`.
+    pub fn evaluate(&self, selection: u8, outcome: &[u8]) -> Result<u64> {
+        let hit = match self {
+            SideBetKind::PlayerPair => Coup::deal(outcome)?.player_pair,
+            SideBetKind::BankerPair => Coup::deal(outcome)?.banker_pair,
+            SideBetKind::LuckyNumber => {
+                require!(!outcome.is_empty(), crate::errors::CasinoError::InvalidRandomnessSource);
+                outcome[0] == selection
+            },
+        };
+        Ok(if hit { self.max_multiplier(selection) } else { 0 })
+    }
+}
+
+impl SideBet {
+    pub fn potential_payout(&self) -> Result<u64> {
+        multiply(self.amount, self.kind.max_multiplier(self.selection))
+    }
+
+    pub fn payout(&self, outcome: &[u8]) -> Result<u64> {
+        multiply(self.amount, self.kind.evaluate(self.selection, outcome)?)
+    }
+}
+
+fn multiply(amount: u64, multiplier: u64) -> Result<u64> {
+    let payout = (amount as u128 * multiplier as u128) / BASIS_POINTS as u128;
+    u64::try_from(payout).map_err(|_| crate::errors::CasinoError::ArithmeticOverflow.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::SHOE_DECKS;
+
+    #[test]
+    fn pair_rtp() {
+        // The second card pairs the first with probability 31 / 415 in an eight-deck shoe
+        let copies = 4 * SHOE_DECKS as u64;
+        let cards = 52 * SHOE_DECKS as u64;
+        let rtp = BACCARAT_PAIR_PAYOUT as f64 * (copies - 1) as f64 / (cards - 1) as f64 / BASIS_POINTS as f64;
+        assert!((rtp - 0.89639).abs() < 1e-5, "{rtp}");
+    }
+
+    #[test]
+    fn pairs_only_back_baccarat() {
+        assert!(SideBetKind::PlayerPair.is_allowed_for(GameType::Baccarat));
+        assert!(!SideBetKind::BankerPair.is_allowed_for(GameType::Blackjack));
+        assert!(!SideBetKind::LuckyNumber.is_allowed_for(GameType::Baccarat));
+    }
+
+    #[test]
+    fn lucky_number_rtp() {
+        // Weight each roll by the outcome bytes that produce it
+        for selection in [1, 56, 57, 100] {
+            let total: u64 = (0..=255u8)
+                .map(|byte| SideBetKind::LuckyNumber.evaluate(selection, &[byte % 100 + 1]).unwrap())
+                .sum();
+            let rtp = total as f64 / 256.0 / BASIS_POINTS as f64;
+            assert!((0.945..0.95).contains(&rtp), "{selection}: {rtp}");
+        }
+    }
+}
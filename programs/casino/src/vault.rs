@@ -0,0 +1,367 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke;
+use anchor_lang::system_program;
+use anchor_spl::token_2022::spl_token_2022;
+use anchor_spl::token_2022::spl_token_2022::extension::{
+    transfer_fee::{self, TransferFeeConfig},
+    BaseStateWithExtensions, ExtensionType, StateWithExtensions,
+};
+use anchor_spl::token_interface::{self, InitializeAccount3, Mint, TokenAccount, TransferChecked};
+
+use crate::errors::CasinoError;
+use crate::state::{Casino, VaultRef, CASINO_SEED, VAULT_SEED};
+
+// Funds movement in and out of a casino vault.
+//
+// SPL vaults are token accounts owned by the casino PDA, one per accepted mint,
+// under either the Token or Token-2022 program, at `[VAULT_SEED, casino]` for the
+// primary mint and `[VAULT_SEED, casino_mint]` for the others. Native SOL casinos
+// keep lamports in a system-owned PDA at `[VAULT_SEED, casino]`, whose rent-exempt
+// minimum is never counted as bankroll. Only a casino's primary mint can be native. For native
+// vaults the player "token account" is simply the player's wallet.
+
+/// Token-2022 mint extensions that do not interfere with bets and payouts
+const SUPPORTED_MINT_EXTENSIONS: [ExtensionType; 4] = [
+    ExtensionType::TransferFeeConfig,
+    ExtensionType::MetadataPointer,
+    ExtensionType::TokenMetadata,
+    ExtensionType::InterestBearingConfig,
+];
+
+/// Rejects Token-2022 mints with extensions the casino cannot handle, such as
+/// non-transferable tokens, transfer hooks or permanent delegates
+pub fn validate_mint(mint: &AccountInfo) -> Result<()> {
+    if *mint.owner != spl_token_2022::ID {
+        return Ok(());
+    }
+
+    let data = mint.try_borrow_data()?;
+    let state = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&data)?;
+    for extension in state.get_extension_types()? {
+        require!(
+            SUPPORTED_MINT_EXTENSIONS.contains(&extension),
+            CasinoError::UnsupportedMintExtension
+        );
+    }
+    Ok(())
+}
+
+/// Creates a casino's primary vault at the `[VAULT_SEED, casino]` PDA: a system account
+/// funded to its rent-exempt minimum for native SOL, or a token account owned by the
+/// casino PDA otherwise
+pub fn initialize<'info>(
+    casino: &AccountInfo<'info>,
+    vault: &AccountInfo<'info>,
+    vault_bump: u8,
+    mint: &InterfaceAccount<'info, Mint>,
+    payer: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    let rent = Rent::get()?;
+
+    if mint.key() == spl_token::native_mint::ID {
+        let shortfall = rent.minimum_balance(0).saturating_sub(vault.lamports());
+        if shortfall > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    system_program.clone(),
+                    system_program::Transfer {
+                        from: payer.clone(),
+                        to: vault.clone(),
+                    },
+                ),
+                shortfall,
+            )?;
+        }
+        return Ok(());
+    }
+
+    let mint_info = mint.to_account_info();
+    require_keys_eq!(*mint_info.owner, token_program.key(), CasinoError::InvalidProgramId);
+
+    let space = token_account_len(&mint_info)?;
+    let casino_key = casino.key();
+    let seeds = &[VAULT_SEED, casino_key.as_ref(), &[vault_bump]];
+    let signer = &[&seeds[..]];
+
+    system_program::create_account(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            system_program::CreateAccount {
+                from: payer.clone(),
+                to: vault.clone(),
+            },
+            signer,
+        ),
+        rent.minimum_balance(space),
+        space as u64,
+        token_program.key,
+    )?;
+    token_interface::initialize_account3(CpiContext::new(
+        token_program.clone(),
+        InitializeAccount3 {
+            account: vault.clone(),
+            mint: mint_info,
+            authority: casino.clone(),
+        },
+    ))
+}
+
+/// Size of a token account for `mint`, including the extensions Token-2022 requires
+fn token_account_len(mint: &AccountInfo) -> Result<usize> {
+    let extensions = if *mint.owner == spl_token_2022::ID {
+        let data = mint.try_borrow_data()?;
+        let state = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&data)?;
+        ExtensionType::get_required_init_account_extensions(&state.get_extension_types()?)
+    } else {
+        Vec::new()
+    };
+    Ok(ExtensionType::try_calculate_account_len::<spl_token_2022::state::Account>(&extensions)?)
+}
+
+/// Transfer fee withheld by a Token-2022 mint when moving `amount`
+pub fn transfer_fee(mint: &AccountInfo, amount: u64) -> Result<u64> {
+    if *mint.owner != spl_token_2022::ID {
+        return Ok(0);
+    }
+
+    let data = mint.try_borrow_data()?;
+    let state = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&data)?;
+    match state.get_extension::<TransferFeeConfig>() {
+        Ok(config) => config
+            .calculate_epoch_fee(Clock::get()?.epoch, amount)
+            .ok_or(CasinoError::ArithmeticOverflow.into()),
+        Err(_) => Ok(0),
+    }
+}
+
+/// Amount that actually arrives after the mint's transfer fee
+pub fn amount_after_fee(mint: &AccountInfo, amount: u64) -> Result<u64> {
+    amount
+        .checked_sub(transfer_fee(mint, amount)?)
+        .ok_or(CasinoError::ArithmeticUnderflow.into())
+}
+
+/// Amount to send so that `net` arrives after the mint's transfer fee
+pub fn amount_before_fee(mint: &AccountInfo, net: u64) -> Result<u64> {
+    if *mint.owner != spl_token_2022::ID {
+        return Ok(net);
+    }
+
+    let data = mint.try_borrow_data()?;
+    let state = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&data)?;
+    match state.get_extension::<TransferFeeConfig>() {
+        Ok(config) => config
+            .calculate_inverse_epoch_fee(Clock::get()?.epoch, net)
+            .and_then(|fee| net.checked_add(fee))
+            .ok_or(CasinoError::ArithmeticOverflow.into()),
+        Err(_) => Ok(net),
+    }
+}
+
+/// Moves the transfer fees withheld in `account` to its mint, so that the account
+/// can be closed. Does nothing unless the mint charges a Token-2022 transfer fee
+pub fn harvest_withheld_fees<'info>(
+    account: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+) -> Result<()> {
+    if *mint.owner != spl_token_2022::ID {
+        return Ok(());
+    }
+    {
+        let data = mint.try_borrow_data()?;
+        let state = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&data)?;
+        if state.get_extension::<TransferFeeConfig>().is_err() {
+            return Ok(());
+        }
+    }
+
+    let instruction = transfer_fee::instruction::harvest_withheld_tokens_to_mint(
+        token_program.key,
+        mint.key,
+        &[account.key],
+    )?;
+    invoke(&instruction, &[mint.clone(), account.clone(), token_program.clone()])?;
+    Ok(())
+}
+
+/// Spendable vault balance in token units (lamports for native casinos)
+pub fn balance(vault_ref: VaultRef, vault: &AccountInfo) -> Result<u64> {
+    require_keys_eq!(vault.key(), vault_ref.vault, CasinoError::InvalidTokenAccount);
+
+    if vault_ref.is_native() {
+        let rent_exempt_minimum = Rent::get()?.minimum_balance(0);
+        Ok(vault.lamports().saturating_sub(rent_exempt_minimum))
+    } else {
+        let vault_account = TokenAccount::try_deserialize(&mut &vault.try_borrow_data()?[..])?;
+        Ok(vault_account.amount)
+    }
+}
+
+/// Moves a player's stake into the vault and returns the amount credited after
+/// any transfer fee
+#[allow(clippy::too_many_arguments)]
+pub fn deposit<'info>(
+    vault_ref: VaultRef,
+    vault: &AccountInfo<'info>,
+    mint: &InterfaceAccount<'info, Mint>,
+    from: &AccountInfo<'info>,
+    player: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<u64> {
+    require_keys_eq!(vault.key(), vault_ref.vault, CasinoError::InvalidTokenAccount);
+    require_keys_eq!(mint.key(), vault_ref.mint, CasinoError::InvalidTokenMint);
+
+    if vault_ref.is_native() {
+        require_keys_eq!(from.key(), player.key(), CasinoError::InvalidTokenAccount);
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                system_program::Transfer {
+                    from: player.clone(),
+                    to: vault.clone(),
+                },
+            ),
+            amount,
+        )?;
+        Ok(amount)
+    } else {
+        validate_token_account(vault_ref, from, &player.key(), token_program)?;
+        token_interface::transfer_checked(
+            CpiContext::new(
+                token_program.clone(),
+                TransferChecked {
+                    from: from.clone(),
+                    mint: mint.to_account_info(),
+                    to: vault.clone(),
+                    authority: player.clone(),
+                },
+            ),
+            amount,
+            mint.decimals,
+        )?;
+        amount_after_fee(&mint.to_account_info(), amount)
+    }
+}
+
+/// Pays out of the vault to `recipient`, signing as the casino or vault PDA, and
+/// returns the amount received after any transfer fee
+#[allow(clippy::too_many_arguments)]
+pub fn withdraw<'info>(
+    casino: &Account<'info, Casino>,
+    vault_ref: VaultRef,
+    vault: &AccountInfo<'info>,
+    mint: &InterfaceAccount<'info, Mint>,
+    to: &AccountInfo<'info>,
+    recipient: &Pubkey,
+    token_program: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<u64> {
+    require_keys_eq!(vault.key(), vault_ref.vault, CasinoError::InvalidTokenAccount);
+    require_keys_eq!(mint.key(), vault_ref.mint, CasinoError::InvalidTokenMint);
+    if amount == 0 {
+        return Ok(0);
+    }
+
+    if vault_ref.is_native() {
+        require_keys_eq!(to.key(), *recipient, CasinoError::InvalidTokenAccount);
+
+        let casino_key = casino.key();
+        let seeds = &[VAULT_SEED, casino_key.as_ref(), &[casino.vault_bump]];
+        let signer = &[&seeds[..]];
+
+        system_program::transfer(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                system_program::Transfer {
+                    from: vault.clone(),
+                    to: to.clone(),
+                },
+                signer,
+            ),
+            amount,
+        )?;
+        Ok(amount)
+    } else {
+        validate_token_account(vault_ref, to, recipient, token_program)?;
+
+        let seeds = &[CASINO_SEED, casino.token_mint.as_ref(), &[casino.bump]];
+        let signer = &[&seeds[..]];
+
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                token_program.clone(),
+                TransferChecked {
+                    from: vault.clone(),
+                    mint: mint.to_account_info(),
+                    to: to.clone(),
+                    authority: casino.to_account_info(),
+                },
+                signer,
+            ),
+            amount,
+            mint.decimals,
+        )?;
+        amount_after_fee(&mint.to_account_info(), amount)
+    }
+}
+
+/// Pays a player `amount` out of the vault, grossed up so that the full amount
+/// arrives despite any transfer fee, which the bankroll absorbs
+#[allow(clippy::too_many_arguments)]
+pub fn pay_out<'info>(
+    casino: &Account<'info, Casino>,
+    vault_ref: VaultRef,
+    vault: &AccountInfo<'info>,
+    mint: &InterfaceAccount<'info, Mint>,
+    to: &AccountInfo<'info>,
+    recipient: &Pubkey,
+    token_program: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<u64> {
+    let gross = amount_before_fee(&mint.to_account_info(), amount)?;
+    withdraw(casino, vault_ref, vault, mint, to, recipient, token_program, system_program, gross)
+}
+
+/// Wallet that receives payouts sent to `account`: the token account's owner, or
+/// the account itself for native vaults
+pub fn recipient_of(vault_ref: VaultRef, account: &AccountInfo) -> Result<Pubkey> {
+    if vault_ref.is_native() {
+        return Ok(account.key());
+    }
+
+    let token_account = TokenAccount::try_deserialize(&mut &account.try_borrow_data()?[..])?;
+    Ok(token_account.owner)
+}
+
+/// Checks that `account` can be paid from the vault: a token account for its mint,
+/// or a system-owned wallet for native vaults
+pub fn validate_recipient(vault_ref: VaultRef, account: &AccountInfo, token_program: &AccountInfo) -> Result<()> {
+    if vault_ref.is_native() {
+        require_keys_eq!(*account.owner, system_program::ID, CasinoError::InvalidAccountOwner);
+        return Ok(());
+    }
+
+    let owner = recipient_of(vault_ref, account)?;
+    validate_token_account(vault_ref, account, &owner, token_program)
+}
+
+fn validate_token_account(
+    vault_ref: VaultRef,
+    account: &AccountInfo,
+    owner: &Pubkey,
+    token_program: &AccountInfo,
+) -> Result<()> {
+    require_keys_eq!(*account.owner, token_program.key(), CasinoError::InvalidAccountOwner);
+
+    let token_account = TokenAccount::try_deserialize(&mut &account.try_borrow_data()?[..])?;
+    require_keys_eq!(token_account.owner, *owner, CasinoError::InvalidTokenAccount);
+    require_keys_eq!(token_account.mint, vault_ref.mint, CasinoError::InvalidTokenMint);
+    Ok(())
+}